@@ -0,0 +1,56 @@
+//! Compares Horner evaluation against summing `c * x.powi(d)` term by term.
+//!
+//! Run with `cargo bench --bench evaluation`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use numerical::Polynomial;
+
+const GRID_SIZE: usize = 10_000;
+const REPEATS: usize = 20;
+
+fn naive(p: &Polynomial, x: f64) -> f64 {
+    p.terms().map(|(d, c)| c * x.powi(d)).sum()
+}
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..REPEATS {
+        f();
+    }
+    start.elapsed() / REPEATS as u32
+}
+
+fn report(name: &str, p: &Polynomial, grid: &[f64]) {
+    let naive_time = time(|| {
+        black_box(grid.iter().map(|&x| naive(p, x)).collect::<Vec<_>>());
+    });
+    let horner_time = time(|| {
+        black_box(p.compute_many(black_box(grid)));
+    });
+    let compensated_time = time(|| {
+        black_box(
+            grid.iter()
+                .map(|&x| p.compute_compensated(x))
+                .collect::<Vec<_>>(),
+        );
+    });
+
+    println!("{name}");
+    println!("  naive powi sum:    {naive_time:?}");
+    println!("  horner:            {horner_time:?}");
+    println!("  compensated:       {compensated_time:?}");
+}
+
+fn main() {
+    let grid: Vec<f64> = (0..GRID_SIZE)
+        .map(|i| -1.0 + 2.0 * i as f64 / (GRID_SIZE - 1) as f64)
+        .collect();
+
+    let dense = Polynomial::from_coefficients((1..=64).map(f64::from).collect());
+    report("dense, degree 63", &dense, &grid);
+
+    let sparse = Polynomial::from_terms([(0, 1.0), (100, -2.0), (1000, 3.0)]);
+    report("sparse, 3 terms up to degree 1000", &sparse, &grid);
+}
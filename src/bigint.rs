@@ -0,0 +1,538 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+use crate::coefficient::Coefficient;
+use crate::error::{NumericalError, ParseError, ParseErrorKind};
+
+/// An arbitrary-precision signed integer.
+///
+/// The magnitude is stored as little-endian base-2^32 digits without leading
+/// zeros, so zero has no digits and is never negative.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+fn trim(magnitude: &mut Vec<u32>) {
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+}
+
+fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+
+    for (i, &digit) in long.iter().enumerate() {
+        let total = digit as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+        sum.push(total as u32);
+        carry = total >> 32;
+    }
+    if carry > 0 {
+        sum.push(carry as u32);
+    }
+
+    sum
+}
+
+/// Computes `a - b` for `a >= b`.
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+
+    for (i, &digit) in a.iter().enumerate() {
+        let mut total = digit as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        borrow = 0;
+        if total < 0 {
+            total += 1 << 32;
+            borrow = 1;
+        }
+        difference.push(total as u32);
+    }
+
+    trim(&mut difference);
+    difference
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let total = x as u64 * y as u64 + product[i + j] as u64 + carry;
+            product[i + j] = total as u32;
+            carry = total >> 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+
+    trim(&mut product);
+    product
+}
+
+/// Divides by a single digit, returning the quotient and remainder.
+fn div_rem_digit(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = 0u64;
+
+    for (i, &digit) in a.iter().enumerate().rev() {
+        let current = (remainder << 32) | digit as u64;
+        quotient[i] = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+
+    trim(&mut quotient);
+    (quotient, remainder as u32)
+}
+
+fn shl_bits(a: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return a.to_vec();
+    }
+    let mut shifted = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u32;
+    for &digit in a {
+        shifted.push((digit << shift) | carry);
+        carry = digit >> (32 - shift);
+    }
+    shifted.push(carry);
+    shifted
+}
+
+/// Knuth's algorithm D for magnitudes, returning the quotient and remainder.
+fn div_rem_magnitude(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    assert!(!v.is_empty(), "attempt to divide by zero");
+
+    if cmp_magnitude(u, v) == Ordering::Less {
+        return (Vec::new(), u.to_vec());
+    }
+    if v.len() == 1 {
+        let (quotient, remainder) = div_rem_digit(u, v[0]);
+        let mut remainder = vec![remainder];
+        trim(&mut remainder);
+        return (quotient, remainder);
+    }
+
+    let n = v.len();
+    let m = u.len() - n;
+    let shift = v[n - 1].leading_zeros();
+    let mut vn = shl_bits(v, shift);
+    vn.truncate(n);
+    let mut un = shl_bits(u, shift);
+    if un.len() == u.len() {
+        un.push(0);
+    }
+
+    let base = 1u64 << 32;
+    let mut quotient = vec![0u32; m + 1];
+
+    for j in (0..=m).rev() {
+        let numerator = ((un[j + n] as u64) << 32) | un[j + n - 1] as u64;
+        let mut q_hat = numerator / vn[n - 1] as u64;
+        let mut r_hat = numerator % vn[n - 1] as u64;
+
+        while q_hat >= base || q_hat * vn[n - 2] as u64 > ((r_hat << 32) | un[j + n - 2] as u64) {
+            q_hat -= 1;
+            r_hat += vn[n - 1] as u64;
+            if r_hat >= base {
+                break;
+            }
+        }
+
+        let mut borrow = 0i64;
+        for i in 0..n {
+            let product = q_hat * vn[i] as u64;
+            let t = un[i + j] as i64 - borrow - (product & 0xFFFF_FFFF) as i64;
+            un[i + j] = t as u32;
+            borrow = (product >> 32) as i64 - (t >> 32);
+        }
+        let t = un[j + n] as i64 - borrow;
+        un[j + n] = t as u32;
+
+        quotient[j] = q_hat as u32;
+        if t < 0 {
+            quotient[j] = quotient[j].wrapping_sub(1);
+            let mut carry = 0u64;
+            for i in 0..n {
+                let total = un[i + j] as u64 + vn[i] as u64 + carry;
+                un[i + j] = total as u32;
+                carry = total >> 32;
+            }
+            un[j + n] = un[j + n].wrapping_add(carry as u32);
+        }
+    }
+
+    let mut remainder: Vec<u32> = (0..n)
+        .map(|i| {
+            if shift == 0 {
+                un[i]
+            } else {
+                (un[i] >> shift) | (un[i + 1] << (32 - shift))
+            }
+        })
+        .collect();
+
+    trim(&mut quotient);
+    trim(&mut remainder);
+    (quotient, remainder)
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        trim(&mut magnitude);
+        let negative = negative && !magnitude.is_empty();
+        Self {
+            negative,
+            magnitude,
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> BigInt {
+        Self::from_parts(false, self.magnitude.clone())
+    }
+
+    /// `-1`, `0` or `1` according to the sign.
+    pub fn signum(&self) -> i32 {
+        match (self.negative, self.magnitude.is_empty()) {
+            (_, true) => 0,
+            (true, false) => -1,
+            (false, false) => 1,
+        }
+    }
+
+    /// Number of bits in the magnitude.
+    pub fn bits(&self) -> u64 {
+        match self.magnitude.last() {
+            Some(&top) => 32 * self.magnitude.len() as u64 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// Multiplies by `2^shift`.
+    pub fn shl(&self, shift: u64) -> BigInt {
+        let mut magnitude = vec![0u32; (shift / 32) as usize];
+        magnitude.extend(shl_bits(&self.magnitude, (shift % 32) as u32));
+        Self::from_parts(self.negative, magnitude)
+    }
+
+    /// Truncating division and remainder, matching the primitive integers:
+    /// the quotient rounds toward zero and the remainder takes the sign of
+    /// `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &BigInt) -> (BigInt, BigInt) {
+        let (quotient, remainder) = div_rem_magnitude(&self.magnitude, &divisor.magnitude);
+        (
+            Self::from_parts(self.negative != divisor.negative, quotient),
+            Self::from_parts(self.negative, remainder),
+        )
+    }
+
+    /// The non-negative greatest common divisor.
+    pub fn gcd(&self, other: &BigInt) -> BigInt {
+        let mut a = self.abs();
+        let mut b = other.abs();
+        while !b.magnitude.is_empty() {
+            let remainder = a.div_rem(&b).1;
+            a = b;
+            b = remainder;
+        }
+        a
+    }
+
+    /// `self^exponent` by repeated squaring.
+    pub fn pow(&self, mut exponent: u32) -> BigInt {
+        let mut base = self.clone();
+        let mut result = BigInt::from(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// The nearest `f64`, or an infinity if the value is out of range.
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.bits();
+        // Keep the top 64 bits, folding any discarded bits into the lowest
+        // one so the conversion to `f64` still rounds correctly.
+        let (digits, exponent, sticky) = if bits > 64 {
+            let shift = bits - 64;
+            let (shifted, rest) =
+                div_rem_magnitude(&self.magnitude, &BigInt::from(1).shl(shift).magnitude);
+            (shifted, shift, !rest.is_empty())
+        } else {
+            (self.magnitude.clone(), 0, false)
+        };
+
+        let top = digits
+            .iter()
+            .rev()
+            .fold(0u64, |acc, &d| (acc << 32) | d as u64)
+            | sticky as u64;
+        let value = top as f64 * 2f64.powi(exponent.min(i32::MAX as u64) as i32);
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BigInt {
+            fn from(value: $t) -> Self {
+                let mut magnitude = value.unsigned_abs() as u128;
+                let mut digits = Vec::new();
+                while magnitude > 0 {
+                    digits.push(magnitude as u32);
+                    magnitude >>= 32;
+                }
+                Self::from_parts(value < 0, digits)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BigInt {
+            fn from(value: $t) -> Self {
+                let mut magnitude = value as u128;
+                let mut digits = Vec::new();
+                while magnitude > 0 {
+                    digits.push(magnitude as u32);
+                    magnitude >>= 32;
+                }
+                Self::from_parts(false, digits)
+            }
+        }
+    )*};
+}
+
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => cmp_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(
+                self.negative,
+                add_magnitude(&self.magnitude, &rhs.magnitude),
+            );
+        }
+        match cmp_magnitude(&self.magnitude, &rhs.magnitude) {
+            Ordering::Less => {
+                BigInt::from_parts(rhs.negative, sub_magnitude(&rhs.magnitude, &self.magnitude))
+            }
+            _ => BigInt::from_parts(
+                self.negative,
+                sub_magnitude(&self.magnitude, &rhs.magnitude),
+            ),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &-rhs
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            mul_magnitude(&self.magnitude, &rhs.magnitude),
+        )
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+/// Implements the owned forms of a binary operator in terms of the
+/// borrowed one.
+macro_rules! forward_owned {
+    ($t:ty, $($imp:ident, $method:ident);*) => {$(
+        impl $imp for $t {
+            type Output = $t;
+
+            fn $method(self, rhs: $t) -> $t {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl $imp<&$t> for $t {
+            type Output = $t;
+
+            fn $method(self, rhs: &$t) -> $t {
+                (&self).$method(rhs)
+            }
+        }
+    )*};
+}
+
+pub(crate) use forward_owned;
+
+forward_owned!(BigInt, Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem);
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Coefficient for BigInt {
+    fn zero() -> Self {
+        BigInt::default()
+    }
+
+    fn one() -> Self {
+        BigInt::from(1)
+    }
+
+    fn from_i32(n: i32) -> Self {
+        BigInt::from(n)
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.to_f64().abs()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u32 = 1_000_000_000;
+
+        let mut chunks = Vec::new();
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = div_rem_digit(&magnitude, CHUNK);
+            chunks.push(remainder);
+            magnitude = quotient;
+        }
+
+        let mut digits = chunks
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, chunk)| {
+                if i == 0 {
+                    chunk.to_string()
+                } else {
+                    format!("{:09}", chunk)
+                }
+            })
+            .collect::<String>();
+        if digits.is_empty() {
+            digits.push('0');
+        }
+
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = NumericalError;
+
+    /// Parses an optionally signed decimal integer.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let invalid = digits.bytes().position(|b| !b.is_ascii_digit());
+        if digits.is_empty() || invalid.is_some() {
+            return Err(ParseError {
+                offset: s.len() - digits.len() + invalid.unwrap_or(0),
+                kind: ParseErrorKind::InvalidNumber,
+            }
+            .into());
+        }
+
+        let ten = BigInt::from(10);
+        let value = digits.bytes().fold(BigInt::default(), |acc, b| {
+            &(&acc * &ten) + &BigInt::from(b - b'0')
+        });
+
+        Ok(if negative { -value } else { value })
+    }
+}
@@ -0,0 +1,459 @@
+//! Argument handling and subcommands of the `numerical` binary.
+
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::io::Read;
+
+use numerical::{FitBasis, FitOptions, NumericalError, ParseError, ParseErrorKind, Polynomial};
+
+pub const USAGE: &str = "\
+usage: numerical <command> [options]
+
+commands:
+  eval <poly> --at X[,X...]            evaluate at one or more points
+  diff <poly> [--order N]              differentiate N times (default 1)
+  integrate <poly> [--constant C]      antiderivative with constant term C
+  integrate <poly> --from A --to B     definite integral over [A, B]
+  roots <poly> [--real]                complex roots, or only real ones
+  fit <data.csv> --degree N            least-squares fit to x,y[,weight] rows
+       [--basis monomial|orthogonal]
+  plot <poly> [--from A] [--to B]      ASCII plot (default [-5, 5])
+       [--width W] [--height H]
+  repl                                 interactive session (:help inside)
+
+<poly> is a polynomial such as \"3x^2 - 2x + 1\", @FILE to read it from a
+file (text or JSON) or - to read it from stdin. <data.csv> may also be -.
+
+options:
+  --format text|json                   output format (default text)
+  --help                               show this message
+
+exit status: 0 on success, 1 on a numerical or parse error, 2 on a usage
+error and 3 if an input cannot be read.";
+
+/// Everything that can make the binary fail, with its exit code.
+#[derive(Debug)]
+pub enum CliError {
+    /// The command line itself is malformed.
+    Usage(String),
+    /// Reading an input failed.
+    Io(String),
+    Numerical(NumericalError),
+}
+
+impl CliError {
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            CliError::Usage(_) => 2,
+            CliError::Io(_) => 3,
+            CliError::Numerical(_) => 1,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) | CliError::Io(message) => write!(f, "{}", message),
+            CliError::Numerical(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl From<NumericalError> for CliError {
+    fn from(error: NumericalError) -> Self {
+        CliError::Numerical(error)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// Positional arguments, `--name value` options and `--flag`s.
+struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+    flags: Vec<String>,
+}
+
+/// Options that take no value.
+const FLAGS: &[&str] = &["real", "help"];
+
+impl Args {
+    fn parse(args: &[String]) -> Result<Self, CliError> {
+        let mut parsed = Args {
+            positional: Vec::new(),
+            options: HashMap::new(),
+            flags: Vec::new(),
+        };
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.strip_prefix("--") {
+                Some(name) if FLAGS.contains(&name) => parsed.flags.push(name.to_string()),
+                Some(name) => {
+                    let value = iter
+                        .next()
+                        .ok_or_else(|| CliError::Usage(format!("--{} needs a value", name)))?;
+                    parsed.options.insert(name.to_string(), value.clone());
+                }
+                None => parsed.positional.push(arg.clone()),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|f| f == name)
+    }
+
+    fn number<T: std::str::FromStr>(&self, name: &str) -> Result<Option<T>, CliError> {
+        self.options
+            .get(name)
+            .map(|value| {
+                value.parse().map_err(|_| {
+                    CliError::Usage(format!("invalid value for --{}: {}", name, value))
+                })
+            })
+            .transpose()
+    }
+
+    fn format(&self) -> Result<Format, CliError> {
+        match self.options.get("format").map(String::as_str) {
+            None | Some("text") => Ok(Format::Text),
+            Some("json") => Ok(Format::Json),
+            Some(other) => Err(CliError::Usage(format!("unknown format: {}", other))),
+        }
+    }
+
+    fn input(&self, what: &str) -> Result<&str, CliError> {
+        match self.positional.get(1) {
+            Some(input) => Ok(input),
+            None => Err(CliError::Usage(format!("missing {}", what))),
+        }
+    }
+}
+
+/// Reads `-` from stdin, `@path` (or, for data files, any path) from disk and
+/// returns anything else as is.
+fn read_source(source: &str, always_file: bool) -> Result<String, CliError> {
+    if source == "-" {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|e| CliError::Io(format!("cannot read stdin: {}", e)))?;
+        return Ok(text);
+    }
+
+    let path = match source.strip_prefix('@') {
+        Some(path) => path,
+        None if always_file => source,
+        None => return Ok(source.to_string()),
+    };
+    std::fs::read_to_string(path).map_err(|e| CliError::Io(format!("cannot read {}: {}", path, e)))
+}
+
+pub(crate) fn read_polynomial(source: &str) -> Result<Polynomial, CliError> {
+    let text = read_source(source, false)?;
+    let text = text.trim();
+    if text.starts_with('{') {
+        Ok(Polynomial::from_json(text)?)
+    } else {
+        Ok(text.parse()?)
+    }
+}
+
+fn write_polynomial(p: &Polynomial, format: Format) -> String {
+    match format {
+        Format::Text => format!("{}\n", p),
+        Format::Json => format!("{}\n", p.to_json()),
+    }
+}
+
+/// Formats a number as JSON, writing non-finite values as `null`.
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        format!("{:?}", value)
+    } else {
+        "null".to_string()
+    }
+}
+
+fn json_array(values: impl IntoIterator<Item = f64>) -> String {
+    let items: Vec<String> = values.into_iter().map(json_number).collect();
+    format!("[{}]", items.join(", "))
+}
+
+/// Runs the command line `args` (without the program name), returning what
+/// to print on success.
+pub fn run(args: &[String]) -> Result<String, CliError> {
+    let args = Args::parse(args)?;
+    if args.flag("help") {
+        return Ok(format!("{}\n", USAGE));
+    }
+    let format = args.format()?;
+
+    match args.positional.first().map(String::as_str) {
+        Some("eval") => eval(&args, format),
+        Some("diff") => diff(&args, format),
+        Some("integrate") => integrate(&args, format),
+        Some("roots") => roots(&args, format),
+        Some("fit") => fit(&args, format),
+        Some("plot") => plot(&args, format),
+        Some("repl") => {
+            crate::repl::run().map_err(|e| CliError::Io(format!("repl: {}", e)))?;
+            Ok(String::new())
+        }
+        Some(other) => Err(CliError::Usage(format!("unknown command: {}", other))),
+        None => Err(CliError::Usage("missing command".to_string())),
+    }
+}
+
+fn eval(args: &Args, format: Format) -> Result<String, CliError> {
+    let p = read_polynomial(args.input("polynomial")?)?;
+    let points = args
+        .options
+        .get("at")
+        .ok_or_else(|| CliError::Usage("eval needs --at".to_string()))?;
+    let xs = points
+        .split(',')
+        .map(|x| {
+            x.trim()
+                .parse::<f64>()
+                .map_err(|_| CliError::Usage(format!("invalid point: {}", x)))
+        })
+        .collect::<Result<Vec<f64>, _>>()?;
+    let ys = p.compute_many(&xs);
+
+    Ok(match format {
+        Format::Text => xs.iter().zip(&ys).fold(String::new(), |mut out, (x, y)| {
+            let _ = writeln!(out, "{}\t{}", x, y);
+            out
+        }),
+        Format::Json => format!("{{\"x\": {}, \"y\": {}}}\n", json_array(xs), json_array(ys)),
+    })
+}
+
+fn diff(args: &Args, format: Format) -> Result<String, CliError> {
+    let p = read_polynomial(args.input("polynomial")?)?;
+    let order: u32 = args.number("order")?.unwrap_or(1);
+    if order > i32::MAX as u32 {
+        return Err(CliError::Usage(format!("--order is too large: {}", order)));
+    }
+    Ok(write_polynomial(&p.nth_derivative(order), format))
+}
+
+fn integrate(args: &Args, format: Format) -> Result<String, CliError> {
+    let p = read_polynomial(args.input("polynomial")?)?;
+
+    match (args.number::<f64>("from")?, args.number::<f64>("to")?) {
+        (Some(a), Some(b)) => {
+            let value = p.definite_integral(a, b)?;
+            Ok(match format {
+                Format::Text => format!("{}\n", value),
+                Format::Json => format!("{{\"value\": {}}}\n", json_number(value)),
+            })
+        }
+        (None, None) => {
+            let constant = args.number("constant")?.unwrap_or(0.0);
+            Ok(write_polynomial(&p.integrate(constant)?, format))
+        }
+        _ => Err(CliError::Usage(
+            "--from and --to must be given together".to_string(),
+        )),
+    }
+}
+
+fn roots(args: &Args, format: Format) -> Result<String, CliError> {
+    let p = read_polynomial(args.input("polynomial")?)?;
+
+    if args.flag("real") {
+        let roots = p.real_roots();
+        return Ok(match format {
+            Format::Text => roots.iter().map(|r| format!("{}\n", r)).collect(),
+            Format::Json => format!("{{\"roots\": {}}}\n", json_array(roots)),
+        });
+    }
+
+    let roots = p.roots();
+    Ok(match format {
+        Format::Text => roots.iter().map(|r| format!("{}\n", r)).collect(),
+        Format::Json => {
+            let pairs: Vec<String> = roots.iter().map(|r| json_array([r.re, r.im])).collect();
+            format!("{{\"roots\": [{}]}}\n", pairs.join(", "))
+        }
+    })
+}
+
+/// Samples read from a data file, with weights if every row has a third
+/// column.
+struct Samples {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    weights: Vec<f64>,
+}
+
+fn csv_error(offset: usize, reason: &'static str) -> CliError {
+    CliError::Numerical(
+        ParseError {
+            offset,
+            kind: ParseErrorKind::InvalidFormat(reason),
+        }
+        .into(),
+    )
+}
+
+/// Parses `x,y` or `x,y,weight` rows, skipping blank lines and a
+/// non-numeric header row.
+fn read_samples(text: &str) -> Result<Samples, CliError> {
+    let mut samples = Samples {
+        xs: Vec::new(),
+        ys: Vec::new(),
+        weights: Vec::new(),
+    };
+
+    let mut offset = 0;
+    let mut weighted = None;
+    for (index, line) in text.split_inclusive('\n').enumerate() {
+        let start = offset;
+        offset += line.len();
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Option<Vec<f64>> = line.split(',').map(|f| f.trim().parse().ok()).collect();
+        let (x, y, weight) = match fields.as_deref() {
+            Some(&[x, y]) => (x, y, None),
+            Some(&[x, y, weight]) => (x, y, Some(weight)),
+            _ if index == 0 => continue,
+            _ => return Err(csv_error(start, "expected x,y or x,y,weight on every row")),
+        };
+        if *weighted.get_or_insert(weight.is_some()) != weight.is_some() {
+            return Err(csv_error(
+                start,
+                "either every row or no row must have a weight",
+            ));
+        }
+        samples.xs.push(x);
+        samples.ys.push(y);
+        samples.weights.extend(weight);
+    }
+
+    Ok(samples)
+}
+
+fn fit(args: &Args, format: Format) -> Result<String, CliError> {
+    let data = read_source(args.input("data file")?, true)?;
+    let degree: usize = args
+        .number("degree")?
+        .ok_or_else(|| CliError::Usage("fit needs --degree".to_string()))?;
+    let basis = match args.options.get("basis").map(String::as_str) {
+        None | Some("monomial") => FitBasis::Monomial,
+        Some("orthogonal") => FitBasis::Orthogonal,
+        Some(other) => return Err(CliError::Usage(format!("unknown basis: {}", other))),
+    };
+
+    let samples = read_samples(&data)?;
+    let options = FitOptions {
+        weights: (!samples.weights.is_empty()).then_some(&samples.weights[..]),
+        basis,
+    };
+    let fit = Polynomial::fit_with_options(&samples.xs, &samples.ys, degree, &options)?;
+
+    let errors = (0..=degree).map(|i| fit.covariance[(i, i)].sqrt());
+    Ok(match format {
+        Format::Text => format!(
+            "{}\nR² = {}\ncondition number = {:e}\nstandard errors = {}\n",
+            fit.polynomial,
+            fit.r_squared,
+            fit.condition_number,
+            errors.map(|e| e.to_string()).collect::<Vec<_>>().join(", ")
+        ),
+        Format::Json => format!(
+            "{{\"polynomial\": {}, \"r_squared\": {}, \"condition_number\": {}, \
+             \"standard_errors\": {}, \"residuals\": {}}}\n",
+            fit.polynomial.to_json(),
+            json_number(fit.r_squared),
+            json_number(fit.condition_number),
+            json_array(errors),
+            json_array(fit.residuals.iter().copied())
+        ),
+    })
+}
+
+fn plot(args: &Args, format: Format) -> Result<String, CliError> {
+    let p = read_polynomial(args.input("polynomial")?)?;
+    let from: f64 = args.number("from")?.unwrap_or(-5.0);
+    let to: f64 = args.number("to")?.unwrap_or(5.0);
+    let width: usize = args.number("width")?.unwrap_or(72);
+    let height: usize = args.number("height")?.unwrap_or(20);
+    if from >= to || width < 2 || height < 2 {
+        return Err(CliError::Usage(
+            "plot needs --from < --to and a size of at least 2x2".to_string(),
+        ));
+    }
+
+    let xs: Vec<f64> = (0..width)
+        .map(|i| from + (to - from) * i as f64 / (width - 1) as f64)
+        .collect();
+    let ys = p.compute_many(&xs);
+
+    if format == Format::Json {
+        return Ok(format!(
+            "{{\"x\": {}, \"y\": {}}}\n",
+            json_array(xs),
+            json_array(ys)
+        ));
+    }
+
+    let finite = ys.iter().copied().filter(|y| y.is_finite());
+    let low = finite.clone().fold(f64::INFINITY, f64::min);
+    let high = finite.fold(f64::NEG_INFINITY, f64::max);
+    if !low.is_finite() {
+        return Err(NumericalError::InvalidDomain(
+            "the polynomial has no finite values on the interval".to_string(),
+        )
+        .into());
+    }
+    let (low, high) = if low == high {
+        (low - 1.0, high + 1.0)
+    } else {
+        (low, high)
+    };
+    let row_of = |y: f64| ((high - y) / (high - low) * (height - 1) as f64).round() as usize;
+
+    let mut grid = vec![vec![' '; width]; height];
+    if low <= 0.0 && high >= 0.0 {
+        grid[row_of(0.0)].fill('-');
+    }
+    if from <= 0.0 && to >= 0.0 {
+        let column = ((0.0 - from) / (to - from) * (width - 1) as f64).round() as usize;
+        grid.iter_mut().for_each(|row| row[column] = '|');
+    }
+    for (column, &y) in ys.iter().enumerate() {
+        if y.is_finite() {
+            grid[row_of(y)][column] = '*';
+        }
+    }
+
+    let mut out = format!("{:>12.4} ┐\n", high);
+    for row in grid {
+        out.push_str(&" ".repeat(13));
+        out.extend(row);
+        out.push('\n');
+    }
+    let _ = writeln!(out, "{:>12.4} ┘", low);
+    let _ = writeln!(
+        out,
+        "{:>13}{:<w$}{}",
+        "",
+        from,
+        to,
+        w = width.saturating_sub(to.to_string().len())
+    );
+    Ok(out)
+}
@@ -0,0 +1,141 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Types that can be used as [`Polynomial`](crate::Polynomial) coefficients.
+///
+/// `Div` follows the type's own semantics, so it truncates for integers; the
+/// operations that need exact division are restricted to [`Field`] types.
+pub trait Coefficient:
+    Clone
+    + fmt::Debug
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    /// Converts a degree, used as a factor when differentiating.
+    fn from_i32(n: i32) -> Self;
+
+    /// Size of the value as an `f64`, such as `|x|` for reals and the modulus
+    /// for complex numbers.
+    fn magnitude(&self) -> f64;
+
+    /// Whether arithmetic on the type is exact, as for integers and
+    /// rationals, rather than rounded, as for floating point. Algorithms
+    /// such as [`Polynomial::gcd`](crate::Polynomial::gcd) only apply
+    /// tolerances to inexact types.
+    const EXACT: bool = true;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+}
+
+/// Coefficients whose division is exact, enabling polynomial division and
+/// integration.
+pub trait Field: Coefficient {}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {$(
+        impl Coefficient for $t {
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn one() -> Self {
+                1.0
+            }
+
+            fn from_i32(n: i32) -> Self {
+                n as $t
+            }
+
+            fn magnitude(&self) -> f64 {
+                self.abs() as f64
+            }
+
+            const EXACT: bool = false;
+        }
+
+        impl Field for $t {}
+    )*};
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {$(
+        impl Coefficient for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn from_i32(n: i32) -> Self {
+                n as $t
+            }
+
+            fn magnitude(&self) -> f64 {
+                self.unsigned_abs() as f64
+            }
+        }
+    )*};
+}
+
+impl_float!(f32, f64);
+impl_integer!(i32, i64, i128);
+
+/// Raises `x` to an integer power by repeated squaring, inverting it first
+/// for negative powers.
+pub(crate) fn powi<T: Coefficient>(x: &T, n: i32) -> T {
+    let base = if n < 0 {
+        T::one() / x.clone()
+    } else {
+        x.clone()
+    };
+    power_by_squaring(base, n.unsigned_abs(), T::one())
+}
+
+/// Returns `1 / x` if `x` has an inverse in `T`, which rules out zero and,
+/// for integer types, everything but `±1`.
+pub(crate) fn inverse<T: Coefficient>(x: &T) -> Option<T> {
+    if x.is_zero() {
+        return None;
+    }
+    let inverse = T::one() / x.clone();
+    // Exact division that does not round trip truncated, as integers do.
+    if T::EXACT && inverse.clone() * x.clone() != T::one() {
+        return None;
+    }
+    Some(inverse)
+}
+
+/// Computes `one * base^exponent` by repeated squaring, for any type with an
+/// associative multiplication, such as the matrices
+/// [`Polynomial::evaluate`](crate::Polynomial::evaluate) accepts.
+pub(crate) fn power_by_squaring<T: Clone + Mul<Output = T>>(
+    mut base: T,
+    mut exponent: u32,
+    one: T,
+) -> T {
+    let mut result = one;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base.clone();
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = base.clone() * base;
+        }
+    }
+
+    result
+}
@@ -0,0 +1,92 @@
+use crate::coefficient::{inverse, powi, Coefficient};
+use crate::error::NumericalError;
+use crate::polynomial::Polynomial;
+
+impl<T: Coefficient> Polynomial<T> {
+    /// Returns `p(q(x))`, where `p` is `self` and `q` is `inner`.
+    ///
+    /// Uses Horner's scheme with polynomial arithmetic, raising `inner` to a
+    /// power only across gaps between the sparse degrees. Negative powers
+    /// are only supported when `inner` is a single term whose coefficient
+    /// has an inverse in `T`, so `±1` for integer types; otherwise this
+    /// fails with [`NumericalError::InvalidDomain`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a degree of the result overflows `i32`.
+    pub fn compose(&self, inner: &Polynomial<T>) -> Result<Polynomial<T>, NumericalError> {
+        let Some(&low) = self.degrees.first() else {
+            return Ok(Self::zero());
+        };
+
+        if low < 0 {
+            return match inner.terms().collect::<Vec<_>>()[..] {
+                [(degree, ref scale)] if inverse(scale).is_some() => {
+                    Ok(Self::from_terms(self.terms().map(|(d, c)| {
+                        (
+                            d.checked_mul(degree).expect("degree overflows i32"),
+                            c * powi(scale, d),
+                        )
+                    })))
+                }
+                [_] => Err(NumericalError::InvalidDomain(
+                    "negative powers need the inverse of the inner coefficient".to_string(),
+                )),
+                _ => Err(NumericalError::InvalidDomain(
+                    "negative powers can only be composed with a single term".to_string(),
+                )),
+            };
+        }
+
+        let mut terms = self.terms().rev();
+        let Some((mut degree, coefficient)) = terms.next() else {
+            return Ok(Self::zero());
+        };
+        let mut value = Self::constant(coefficient);
+
+        for (d, c) in terms {
+            value = value * inner.pow((degree - d) as u32) + Self::constant(c);
+            degree = d;
+        }
+
+        Ok(value * inner.pow(degree as u32))
+    }
+
+    /// Raises the polynomial to the `n`-th power by repeated squaring.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a degree of the result overflows `i32`.
+    pub fn pow(&self, n: u32) -> Polynomial<T> {
+        let mut result = Self::constant(T::one());
+        let mut base = self.clone();
+        let mut exponent = n;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+
+        result
+    }
+
+    /// Returns the reversal `x^n p(1/x)`, where `n` is the degree, which
+    /// lists the coefficients in the opposite order. Its roots are the
+    /// reciprocals of the non-zero roots of `p`.
+    pub fn reversal(&self) -> Polynomial<T> {
+        let Some(high) = self.degree() else {
+            return Self::zero();
+        };
+        Self::from_sorted_terms(self.terms().rev().map(|(d, c)| (high - d, c)))
+    }
+
+    /// Returns `p(1/x)`, which turns every `x^d` into `x^-d`.
+    pub fn reciprocal(&self) -> Polynomial<T> {
+        Self::from_sorted_terms(self.terms().rev().map(|(d, c)| (-d, c)))
+    }
+}
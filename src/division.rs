@@ -0,0 +1,50 @@
+use std::collections::BTreeMap;
+
+use crate::coefficient::Field;
+use crate::error::NumericalError;
+use crate::polynomial::Polynomial;
+
+impl<T: Field> Polynomial<T> {
+    /// Divides `self` by `divisor`, returning `(quotient, remainder)` such
+    /// that `self = quotient * divisor + remainder` and every term of the
+    /// remainder has a lower degree than the leading term of `divisor`.
+    ///
+    /// Only the terms actually touched by the division are visited, so
+    /// sparse inputs of very high degree stay cheap.
+    pub fn div_rem(
+        &self,
+        divisor: &Polynomial<T>,
+    ) -> Result<(Polynomial<T>, Polynomial<T>), NumericalError> {
+        let (lead_degree, lead_coefficient) = divisor
+            .terms()
+            .next_back()
+            .ok_or(NumericalError::DivisionByZero)?;
+
+        let mut remainder: BTreeMap<i32, T> = self.terms().collect();
+        let mut quotient: Vec<(i32, T)> = Vec::new();
+
+        while let Some((&degree, _)) = remainder.last_key_value() {
+            if degree < lead_degree {
+                break;
+            }
+            let (_, coefficient) = remainder.pop_last().unwrap();
+            if coefficient.is_zero() {
+                continue;
+            }
+
+            let shift = degree - lead_degree;
+            let factor = coefficient / lead_coefficient.clone();
+
+            for (d, c) in divisor.terms().filter(|&(d, _)| d < lead_degree) {
+                let entry = remainder.entry(d + shift).or_insert_with(T::zero);
+                *entry = entry.clone() - factor.clone() * c;
+            }
+            quotient.push((shift, factor));
+        }
+
+        Ok((
+            Polynomial::from_terms(quotient),
+            Polynomial::from_sorted_terms(remainder),
+        ))
+    }
+}
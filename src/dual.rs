@@ -0,0 +1,58 @@
+use std::ops::{Add, Mul};
+
+use crate::ring::Ring;
+
+/// A dual number `value + derivative * ε` with `ε² = 0`.
+///
+/// Evaluating a polynomial at `Dual::variable(x)` yields `p(x)` and `p'(x)`
+/// together.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dual {
+    pub value: f64,
+    pub derivative: f64,
+}
+
+impl Dual {
+    pub fn new(value: f64, derivative: f64) -> Self {
+        Self { value, derivative }
+    }
+
+    /// The independent variable `x + ε`.
+    pub fn variable(x: f64) -> Self {
+        Self::new(x, 1.0)
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.value * rhs.value,
+            self.value * rhs.derivative + self.derivative * rhs.value,
+        )
+    }
+}
+
+impl Ring for Dual {
+    fn one_like(&self) -> Self {
+        Self::new(1.0, 0.0)
+    }
+
+    fn scale(&self, factor: f64) -> Self {
+        Self::new(self.value * factor, self.derivative * factor)
+    }
+
+    fn recip(&self) -> Self {
+        let inverse = 1.0 / self.value;
+        Self::new(inverse, -self.derivative * inverse * inverse)
+    }
+}
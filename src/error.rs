@@ -0,0 +1,102 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors reported by this crate.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumericalError {
+    /// Two inputs that must pair up element by element differ in length,
+    /// such as the coefficients and degrees passed to
+    /// [`Polynomial::new`](crate::Polynomial::new).
+    LengthMismatch { left: usize, right: usize },
+    /// Division by the zero polynomial.
+    DivisionByZero,
+    /// An iterative method stopped without meeting its tolerance.
+    NonConvergence { iterations: usize, residual: f64 },
+    /// The operation is not defined for the given input, such as integrating
+    /// an `x^-1` term to a polynomial.
+    InvalidDomain(String),
+    /// Text could not be parsed as a polynomial or number.
+    Parse(ParseError),
+    /// A matrix that must be invertible is singular.
+    SingularMatrix,
+}
+
+impl fmt::Display for NumericalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumericalError::LengthMismatch { left, right } => {
+                write!(f, "Lengths do not match: {} and {}", left, right)
+            }
+            NumericalError::DivisionByZero => write!(f, "Division by the zero polynomial"),
+            NumericalError::NonConvergence {
+                iterations,
+                residual,
+            } => write!(
+                f,
+                "Did not converge after {} iterations (residual {:e})",
+                iterations, residual
+            ),
+            NumericalError::InvalidDomain(reason) => write!(f, "Invalid domain: {}", reason),
+            NumericalError::Parse(error) => write!(f, "{}", error),
+            NumericalError::SingularMatrix => write!(f, "Matrix is singular"),
+        }
+    }
+}
+
+impl Error for NumericalError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NumericalError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for NumericalError {
+    fn from(error: ParseError) -> Self {
+        NumericalError::Parse(error)
+    }
+}
+
+/// Why a string could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input has no terms.
+    Empty,
+    UnexpectedCharacter(char),
+    UnexpectedEnd,
+    /// A coefficient literal was rejected by the coefficient type.
+    InvalidCoefficient,
+    /// An exponent is missing or does not fit in an `i32`.
+    InvalidExponent,
+    /// The input is not a valid integer or fraction.
+    InvalidNumber,
+    /// The input is well-formed but does not describe a polynomial, such as
+    /// a JSON document without a `terms` list.
+    InvalidFormat(&'static str),
+}
+
+/// The location and reason of a parse failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Byte offset into the input where the problem was found.
+    pub offset: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::Empty => write!(f, "Empty polynomial")?,
+            ParseErrorKind::UnexpectedCharacter(c) => write!(f, "Unexpected character {:?}", c)?,
+            ParseErrorKind::UnexpectedEnd => write!(f, "Unexpected end of input")?,
+            ParseErrorKind::InvalidCoefficient => write!(f, "Invalid coefficient")?,
+            ParseErrorKind::InvalidExponent => write!(f, "Invalid exponent")?,
+            ParseErrorKind::InvalidNumber => write!(f, "Invalid number")?,
+            ParseErrorKind::InvalidFormat(reason) => write!(f, "Invalid format: {}", reason)?,
+        }
+        write!(f, " at byte {}", self.offset)
+    }
+}
+
+impl Error for ParseError {}
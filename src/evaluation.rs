@@ -0,0 +1,223 @@
+use crate::coefficient::{inverse, power_by_squaring, powi, Coefficient};
+use crate::dual::Dual;
+use crate::polynomial::Polynomial;
+use crate::ring::Ring;
+
+/// Error-free transformation `a + b = s + e`.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let z = s - a;
+    (s, (a - (s - z)) + (b - z))
+}
+
+/// Error-free transformation `a * b = p + e`.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+/// Raises `x` to an integer power, inverting it first for negative powers.
+fn power<R: Ring>(x: &R, n: i32) -> R {
+    let base = if n < 0 { x.recip() } else { x.clone() };
+    power_by_squaring(base, n.unsigned_abs(), x.one_like())
+}
+
+impl<T: Coefficient> Polynomial<T> {
+    /// Evaluates the polynomial at `x`.
+    ///
+    /// Uses Horner's scheme over the stored terms, bridging each gap in the
+    /// sparse degrees with a single power, so the cost grows with the number
+    /// of terms rather than the degree.
+    ///
+    /// # Panics
+    ///
+    /// For exact coefficient types, panics if the polynomial has negative
+    /// powers and `x` has no inverse in `T`: zero, or for integer types
+    /// anything but `±1`, where truncated division would give a wrong value.
+    /// Floating-point types follow IEEE arithmetic instead, so negative
+    /// powers at zero evaluate to an infinity or NaN.
+    pub fn compute(&self, x: T) -> T {
+        if T::EXACT && self.degrees.first().is_some_and(|&d| d < 0) {
+            assert!(
+                inverse(&x).is_some(),
+                "negative powers need the inverse of x"
+            );
+        }
+
+        let mut terms = self.terms().rev();
+        let Some((mut degree, mut value)) = terms.next() else {
+            return T::zero();
+        };
+
+        for (d, c) in terms {
+            value = value * powi(&x, degree - d) + c;
+            degree = d;
+        }
+
+        value * powi(&x, degree)
+    }
+
+    /// Evaluates the polynomial at every point of `xs`.
+    pub fn compute_many(&self, xs: &[T]) -> Vec<T> {
+        xs.iter().map(|x| self.compute(x.clone())).collect()
+    }
+
+    /// Replaces every point of `xs` with the value of the polynomial there.
+    pub fn compute_in_place(&self, xs: &mut [T]) {
+        xs.iter_mut().for_each(|x| *x = self.compute(x.clone()));
+    }
+}
+
+impl Polynomial {
+    /// Evaluates the polynomial at `x` with compensated Horner's scheme.
+    ///
+    /// The rounding error of every step is tracked and added back at the end,
+    /// giving a result about as accurate as Horner's scheme in twice the
+    /// working precision. Each degree between the highest and the lowest (or
+    /// zero) is visited, so the cost grows with the degree span.
+    pub fn compute_compensated(&self, x: f64) -> f64 {
+        let (Some(&low), Some(high)) = (self.degrees.first(), self.degree()) else {
+            return 0.0;
+        };
+        let stop = low.min(0);
+
+        let mut terms = self.terms().rev().peekable();
+        let mut value = 0.0;
+        let mut error = 0.0;
+
+        for degree in (stop..=high).rev() {
+            let coefficient = match terms.peek() {
+                Some(&(d, c)) if d == degree => {
+                    terms.next();
+                    c
+                }
+                _ => 0.0,
+            };
+
+            let (product, product_error) = two_product(value, x);
+            let (sum, sum_error) = two_sum(product, coefficient);
+            value = sum;
+            error = error * x + (product_error + sum_error);
+        }
+
+        (value + error) * x.powi(stop)
+    }
+
+    /// Evaluates the polynomial at any [`Ring`] element, such as a
+    /// [`Complex64`](crate::Complex64), a square [`Matrix`](crate::Matrix) or
+    /// a [`Dual`] number, using the same sparse Horner scheme as
+    /// [`Polynomial::compute`].
+    pub fn evaluate<R: Ring>(&self, x: &R) -> R {
+        let one = x.one_like();
+        let mut terms = self.terms().rev();
+        let Some((mut degree, coefficient)) = terms.next() else {
+            return one.scale(0.0);
+        };
+        let mut value = one.scale(coefficient);
+
+        for (d, c) in terms {
+            value = value * power(x, degree - d) + one.scale(c);
+            degree = d;
+        }
+
+        value * power(x, degree)
+    }
+
+    /// Evaluates the polynomial with the Paterson–Stockmeyer scheme, which
+    /// needs only about `2 * sqrt(n)` non-scalar multiplications for a degree
+    /// span of `n`, at the cost of more scalar multiplications and additions.
+    ///
+    /// This pays off when multiplication is expensive, as for matrices.
+    pub fn evaluate_paterson_stockmeyer<R: Ring>(&self, x: &R) -> R {
+        let one = x.one_like();
+        let (Some(&low), Some(high)) = (self.degrees.first(), self.degree()) else {
+            return one.scale(0.0);
+        };
+        let low = low.min(0);
+        let span = (high - low) as usize + 1;
+
+        let mut dense = vec![0.0; span];
+        for (degree, coefficient) in self.terms() {
+            dense[(degree - low) as usize] = coefficient;
+        }
+
+        let block = (span as f64).sqrt().ceil() as usize;
+        let mut powers = vec![one.clone()];
+        for i in 1..=block {
+            powers.push(powers[i - 1].clone() * x.clone());
+        }
+
+        let mut value: Option<R> = None;
+        for chunk in dense.chunks(block).rev() {
+            let block_value = chunk
+                .iter()
+                .zip(&powers)
+                .filter(|&(&c, _)| c != 0.0)
+                .fold(one.scale(0.0), |acc, (&c, p)| acc + p.scale(c));
+
+            value = Some(match value {
+                Some(v) => v * powers[block].clone() + block_value,
+                None => block_value,
+            });
+        }
+
+        let value = value.unwrap_or_else(|| one.scale(0.0));
+        if low < 0 {
+            value * power(x, low)
+        } else {
+            value
+        }
+    }
+
+    /// Returns `[p(x), p'(x), ..., p⁽ⁿ⁾(x)]`.
+    ///
+    /// The non-negative powers go through extended Horner's scheme, which
+    /// carries all `n + 1` Taylor coefficients at once in `O(n * degree)`
+    /// operations; negative powers are differentiated term by term.
+    pub fn derivatives_at(&self, x: f64, n: usize) -> Vec<f64> {
+        let mut taylor = vec![0.0; n + 1];
+
+        if let Some(high) = self.degree().filter(|&d| d >= 0) {
+            let mut terms = self.terms().rev().peekable();
+            for degree in (0..=high).rev() {
+                let coefficient = match terms.peek() {
+                    Some(&(d, c)) if d == degree => {
+                        terms.next();
+                        c
+                    }
+                    _ => 0.0,
+                };
+
+                // Entries beyond `high - degree` are still zero.
+                for k in (1..=n.min((high - degree) as usize)).rev() {
+                    taylor[k] = taylor[k] * x + taylor[k - 1];
+                }
+                taylor[0] = taylor[0] * x + coefficient;
+            }
+        }
+
+        // The k-th Taylor coefficient is the k-th derivative over k!.
+        let mut factorial = 1.0;
+        for (k, value) in taylor.iter_mut().enumerate().skip(1) {
+            factorial *= k as f64;
+            *value *= factorial;
+        }
+
+        for (degree, coefficient) in self.terms().take_while(|&(d, _)| d < 0) {
+            let mut factor = coefficient;
+            for (k, value) in taylor.iter_mut().enumerate() {
+                *value += factor * x.powi(degree - k as i32);
+                factor *= f64::from(degree - k as i32);
+            }
+        }
+
+        taylor
+    }
+
+    /// Returns `(p(x), p'(x))`, computed together by evaluating at a
+    /// [`Dual`] number.
+    pub fn value_and_derivative(&self, x: f64) -> (f64, f64) {
+        let result = self.evaluate(&Dual::variable(x));
+        (result.value, result.derivative)
+    }
+}
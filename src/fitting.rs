@@ -0,0 +1,268 @@
+use crate::error::NumericalError;
+use crate::matrix::Matrix;
+use crate::polynomial::Polynomial;
+use crate::ring::Ring;
+
+/// The basis a least-squares fit is solved in. The result is always
+/// returned in monomial form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FitBasis {
+    /// Columns `1, x, x^2, ...`; simple, but the system becomes badly
+    /// conditioned as the degree grows or the data moves away from zero.
+    #[default]
+    Monomial,
+    /// Polynomials orthogonal over the weighted sample points after mapping
+    /// them onto `[-1, 1]`, built with the Stieltjes recurrence, which keeps
+    /// the system well conditioned at any degree.
+    Orthogonal,
+}
+
+/// Settings for [`Polynomial::fit_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FitOptions<'a> {
+    /// Non-negative weight of each sample's squared residual, typically
+    /// `1 / σ²`; every sample counts equally if `None`.
+    pub weights: Option<&'a [f64]>,
+    pub basis: FitBasis,
+}
+
+/// A least-squares fit together with its diagnostics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fit {
+    pub polynomial: Polynomial,
+    /// `ys[i] - p(xs[i])` for every sample, unweighted.
+    pub residuals: Vec<f64>,
+    /// The weighted coefficient of determination, `1 - SS_res / SS_tot`.
+    pub r_squared: f64,
+    /// Covariance of the monomial coefficients, in ascending degree, with
+    /// the noise variance estimated from the weighted residuals. It is NaN
+    /// when there are no more samples than coefficients.
+    pub covariance: Matrix,
+    /// Condition number of the weighted design matrix in the basis the fit
+    /// was solved in.
+    pub condition_number: f64,
+}
+
+impl Polynomial {
+    /// Fits a polynomial of the given degree to the samples `(xs[i], ys[i])`
+    /// in the least-squares sense.
+    ///
+    /// The system is solved by QR factorization rather than the normal
+    /// equations, which would square its condition number.
+    pub fn fit(xs: &[f64], ys: &[f64], degree: usize) -> Result<Fit, NumericalError> {
+        Self::fit_with_options(xs, ys, degree, &FitOptions::default())
+    }
+
+    /// Like [`Polynomial::fit`], minimizing `Σ weights[i] (ys[i] - p(xs[i]))²`.
+    pub fn fit_weighted(
+        xs: &[f64],
+        ys: &[f64],
+        weights: &[f64],
+        degree: usize,
+    ) -> Result<Fit, NumericalError> {
+        let options = FitOptions {
+            weights: Some(weights),
+            ..FitOptions::default()
+        };
+        Self::fit_with_options(xs, ys, degree, &options)
+    }
+
+    /// Fits a polynomial of the given degree with the given weights and basis.
+    ///
+    /// Fails with [`NumericalError::LengthMismatch`] if the inputs differ in
+    /// length, [`NumericalError::InvalidDomain`] for negative or non-finite
+    /// weights or fewer samples than coefficients, and
+    /// [`NumericalError::SingularMatrix`] if the samples cannot determine
+    /// every coefficient, for example when too few `xs` are distinct.
+    pub fn fit_with_options(
+        xs: &[f64],
+        ys: &[f64],
+        degree: usize,
+        options: &FitOptions,
+    ) -> Result<Fit, NumericalError> {
+        if xs.len() != ys.len() {
+            return Err(NumericalError::LengthMismatch {
+                left: xs.len(),
+                right: ys.len(),
+            });
+        }
+        // Checked before the design matrix is allocated, so that a huge
+        // degree fails at once instead of exhausting memory.
+        let n = degree
+            .checked_add(1)
+            .filter(|&n| n <= xs.len())
+            .ok_or_else(|| {
+                NumericalError::InvalidDomain(format!(
+                    "a fit of degree {} needs more than {} samples, got {}",
+                    degree,
+                    degree,
+                    xs.len()
+                ))
+            })?;
+        let weights = match options.weights {
+            Some(weights) if weights.len() != xs.len() => {
+                return Err(NumericalError::LengthMismatch {
+                    left: xs.len(),
+                    right: weights.len(),
+                })
+            }
+            Some(weights) if weights.iter().any(|w| !(w.is_finite() && *w >= 0.0)) => {
+                return Err(NumericalError::InvalidDomain(
+                    "weights must be finite and non-negative".to_string(),
+                ))
+            }
+            Some(weights) => weights.to_vec(),
+            None => vec![1.0; xs.len()],
+        };
+
+        let (design, to_monomial) = match options.basis {
+            FitBasis::Monomial => (monomial_design(xs, degree), None),
+            FitBasis::Orthogonal => {
+                let (design, basis) = orthogonal_design(xs, &weights, degree);
+                (design, Some(basis))
+            }
+        };
+
+        let m = xs.len();
+        let mut weighted = design.clone();
+        let mut weighted_ys = ys.to_vec();
+        for i in 0..m {
+            let scale = weights[i].sqrt();
+            for j in 0..n {
+                weighted[(i, j)] *= scale;
+            }
+            weighted_ys[i] *= scale;
+        }
+
+        let (coefficients, r) = weighted.least_squares_qr(&weighted_ys)?;
+
+        let residuals: Vec<f64> = (0..m)
+            .map(|i| {
+                ys[i]
+                    - (0..n)
+                        .map(|j| design[(i, j)] * coefficients[j])
+                        .sum::<f64>()
+            })
+            .collect();
+
+        let total_weight: f64 = weights.iter().sum();
+        let mean = weights.iter().zip(ys).map(|(w, y)| w * y).sum::<f64>() / total_weight;
+        let ss_res: f64 = weights.iter().zip(&residuals).map(|(w, e)| w * e * e).sum();
+        let ss_tot: f64 = weights
+            .iter()
+            .zip(ys)
+            .map(|(w, y)| w * (y - mean) * (y - mean))
+            .sum();
+        let r_squared = if ss_tot > 0.0 {
+            1.0 - ss_res / ss_tot
+        } else {
+            1.0
+        };
+
+        // Cov(c) = σ² (AᵀWA)⁻¹ = σ² R⁻¹R⁻ᵀ, with σ² from the residuals.
+        let variance = if m > n {
+            ss_res / (m - n) as f64
+        } else {
+            f64::NAN
+        };
+        let r_inverse = r.inverse()?;
+        let covariance = (&r_inverse * &r_inverse.transpose()).scale(variance);
+
+        let (coefficients, covariance) = match to_monomial {
+            None => (coefficients, covariance),
+            Some(t) => {
+                let c = (0..n)
+                    .map(|i| (0..n).map(|j| t[(i, j)] * coefficients[j]).sum())
+                    .collect();
+                (c, &(&t * &covariance) * &t.transpose())
+            }
+        };
+
+        Ok(Fit {
+            polynomial: Polynomial::from_coefficients(coefficients),
+            residuals,
+            r_squared,
+            covariance,
+            condition_number: weighted.condition_number(),
+        })
+    }
+}
+
+/// The Vandermonde matrix with columns `1, x, x^2, ...`.
+fn monomial_design(xs: &[f64], degree: usize) -> Matrix {
+    let mut design = Matrix::zeros(xs.len(), degree + 1);
+    for (i, &x) in xs.iter().enumerate() {
+        let mut power = 1.0;
+        for j in 0..=degree {
+            design[(i, j)] = power;
+            power *= x;
+        }
+    }
+    design
+}
+
+/// Evaluates polynomials `φ_0, ..., φ_degree` orthogonal over the weighted
+/// samples, after mapping `xs` onto `[-1, 1]`, with the Stieltjes recurrence
+/// `φ_(k+1) = (t - a_k) φ_k - b_k φ_(k-1)`.
+///
+/// Returns their values at the samples and the matrix whose column `k`
+/// holds the monomial coefficients of `φ_k` as a function of `x`.
+fn orthogonal_design(xs: &[f64], weights: &[f64], degree: usize) -> (Matrix, Matrix) {
+    let low = xs.iter().copied().fold(f64::INFINITY, f64::min);
+    let high = xs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let (scale, offset) = if high > low {
+        (2.0 / (high - low), -(high + low) / (high - low))
+    } else {
+        (1.0, 0.0)
+    };
+    let ts: Vec<f64> = xs.iter().map(|&x| scale * x + offset).collect();
+    let t = Polynomial::from_coefficients(vec![offset, scale]);
+
+    let m = xs.len();
+    let mut design = Matrix::zeros(m, degree + 1);
+    let mut to_monomial = Matrix::zeros(degree + 1, degree + 1);
+
+    let mut previous_values = vec![0.0; m];
+    let mut values = vec![1.0; m];
+    let mut previous = Polynomial::zero();
+    let mut current = Polynomial::constant(1.0);
+    let mut previous_norm = 1.0;
+
+    for k in 0..=degree {
+        for i in 0..m {
+            design[(i, k)] = values[i];
+        }
+        for (d, c) in current.terms() {
+            to_monomial[(d as usize, k)] = c;
+        }
+        if k == degree {
+            break;
+        }
+
+        let norm: f64 = (0..m).map(|i| weights[i] * values[i] * values[i]).sum();
+        let a = if norm > 0.0 {
+            (0..m)
+                .map(|i| weights[i] * ts[i] * values[i] * values[i])
+                .sum::<f64>()
+                / norm
+        } else {
+            0.0
+        };
+        let b = if k > 0 && previous_norm > 0.0 {
+            norm / previous_norm
+        } else {
+            0.0
+        };
+
+        let next_values: Vec<f64> = (0..m)
+            .map(|i| (ts[i] - a) * values[i] - b * previous_values[i])
+            .collect();
+        let next = &(&(&t - &Polynomial::constant(a)) * &current) - &(previous.clone() * b);
+
+        previous_values = std::mem::replace(&mut values, next_values);
+        previous = std::mem::replace(&mut current, next);
+        previous_norm = norm;
+    }
+
+    (design, to_monomial)
+}
@@ -0,0 +1,207 @@
+use std::fmt::{self, Write};
+
+use crate::coefficient::Coefficient;
+use crate::polynomial::Polynomial;
+
+/// The notations a [`Polynomial`] can be rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    /// `3x^2 - 2x + 1`, which [`str::parse`] reads back for real, integer
+    /// and rational coefficients.
+    Plain,
+    /// `3x² - 2x + 1`, which [`str::parse`] also reads back.
+    Unicode,
+    /// `3x^{2} - 2x + 1`, which [`str::parse`] also reads back.
+    Latex,
+    /// Presentation MathML wrapped in a `<math>` element.
+    MathMl,
+}
+
+/// A [`Polynomial`] paired with a [`Notation`], returned by
+/// [`Polynomial::display`].
+///
+/// Like the `Display` impl of `Polynomial`, it honours the precision flag
+/// (`{:.3}`) for coefficients and lists terms in ascending order of degree
+/// with the alternate flag (`{:#}`).
+#[derive(Debug, Clone, Copy)]
+pub struct Formatted<'a, T> {
+    polynomial: &'a Polynomial<T>,
+    notation: Notation,
+}
+
+impl<T> Polynomial<T> {
+    /// Renders the polynomial in the given notation.
+    pub fn display(&self, notation: Notation) -> Formatted<'_, T> {
+        Formatted {
+            polynomial: self,
+            notation,
+        }
+    }
+
+    pub fn latex(&self) -> Formatted<'_, T> {
+        self.display(Notation::Latex)
+    }
+
+    pub fn unicode(&self) -> Formatted<'_, T> {
+        self.display(Notation::Unicode)
+    }
+
+    pub fn mathml(&self) -> Formatted<'_, T> {
+        self.display(Notation::MathMl)
+    }
+}
+
+fn superscript(degree: i32) -> String {
+    degree
+        .to_string()
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            _ => '⁹',
+        })
+        .collect()
+}
+
+/// Formats `|coefficient|`, returning it with whether the coefficient is
+/// negative. Coefficients whose text has its own internal sign, such as
+/// complex numbers, are parenthesized and treated as positive.
+fn magnitude_text<T: fmt::Display>(coefficient: &T, precision: Option<usize>) -> (bool, String) {
+    let text = match precision {
+        Some(p) => format!("{:.*}", p, coefficient),
+        None => coefficient.to_string(),
+    };
+
+    let (negative, rest) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.as_str()),
+    };
+    let compound = rest.chars().enumerate().any(|(i, c)| {
+        c.is_whitespace()
+            || ((c == '+' || c == '-') && !matches!(rest[..i].chars().last(), Some('e' | 'E')))
+    });
+
+    if compound {
+        (false, format!("({})", text))
+    } else {
+        (negative, rest.to_string())
+    }
+}
+
+impl<T: Coefficient + fmt::Display> Formatted<'_, T> {
+    fn write_power(&self, f: &mut fmt::Formatter<'_>, degree: i32) -> fmt::Result {
+        match (self.notation, degree) {
+            (Notation::MathMl, 1) => f.write_str("<mi>x</mi>"),
+            (Notation::MathMl, _) if degree < 0 => write!(
+                f,
+                "<msup><mi>x</mi><mrow><mo>-</mo><mn>{}</mn></mrow></msup>",
+                -degree
+            ),
+            (Notation::MathMl, _) => write!(f, "<msup><mi>x</mi><mn>{}</mn></msup>", degree),
+            (_, 1) => f.write_char('x'),
+            (Notation::Plain, _) => write!(f, "x^{}", degree),
+            (Notation::Unicode, _) => write!(f, "x{}", superscript(degree)),
+            (Notation::Latex, _) => write!(f, "x^{{{}}}", degree),
+        }
+    }
+
+    fn write_operator(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        negative: bool,
+        first: bool,
+    ) -> fmt::Result {
+        let operator = if negative { "-" } else { "+" };
+        match (self.notation, first) {
+            (Notation::MathMl, true) if negative => write!(f, "<mo>{}</mo>", operator),
+            (Notation::MathMl, false) => write!(f, "<mo>{}</mo>", operator),
+            (_, true) if negative => f.write_str(operator),
+            (_, false) => write!(f, " {} ", operator),
+            _ => Ok(()),
+        }
+    }
+
+    fn write_term(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        degree: i32,
+        coefficient: &T,
+        first: bool,
+    ) -> fmt::Result {
+        let unit = degree != 0 && (*coefficient == T::one() || *coefficient == -T::one());
+        if unit {
+            self.write_operator(f, *coefficient != T::one(), first)?;
+            return self.write_power(f, degree);
+        }
+
+        let (negative, text) = magnitude_text(coefficient, f.precision());
+        self.write_operator(f, negative, first)?;
+
+        match (self.notation, text.split_once('/')) {
+            (Notation::Latex, Some((numerator, denominator))) => {
+                write!(f, "\\frac{{{}}}{{{}}}", numerator, denominator)?
+            }
+            (Notation::MathMl, Some((numerator, denominator))) => write!(
+                f,
+                "<mfrac><mn>{}</mn><mn>{}</mn></mfrac>",
+                numerator, denominator
+            )?,
+            (Notation::MathMl, None) => write!(f, "<mn>{}</mn>", text)?,
+            (_, Some(_)) if degree != 0 && !text.starts_with('(') => write!(f, "({})", text)?,
+            _ => f.write_str(&text)?,
+        }
+        if degree == 0 {
+            return Ok(());
+        }
+        if self.notation == Notation::MathMl {
+            f.write_str("<mo>&InvisibleTimes;</mo>")?;
+        }
+        self.write_power(f, degree)
+    }
+}
+
+impl<T: Coefficient + fmt::Display> fmt::Display for Formatted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.notation == Notation::MathMl {
+            f.write_str("<math><mrow>")?;
+        }
+
+        let terms = &self.polynomial;
+        if terms.is_empty() {
+            match self.notation {
+                Notation::MathMl => f.write_str("<mn>0</mn>")?,
+                _ => f.write_char('0')?,
+            }
+        } else if f.alternate() {
+            for (i, (degree, coefficient)) in terms.terms().enumerate() {
+                self.write_term(f, degree, &coefficient, i == 0)?;
+            }
+        } else {
+            for (i, (degree, coefficient)) in terms.terms().rev().enumerate() {
+                self.write_term(f, degree, &coefficient, i == 0)?;
+            }
+        }
+
+        if self.notation == Notation::MathMl {
+            f.write_str("</mrow></math>")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Coefficient + fmt::Display> fmt::Display for Polynomial<T> {
+    /// Writes conventional notation with the highest degree first, such as
+    /// `x^2 + 2x + 1`. `{:.3}` sets the precision of the coefficients and
+    /// `{:#}` lists terms in ascending order of degree instead.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display(Notation::Plain), f)
+    }
+}
@@ -0,0 +1,329 @@
+use std::ops::Rem;
+
+use crate::bigint::BigInt;
+use crate::coefficient::{powi, Coefficient, Field};
+use crate::polynomial::Polynomial;
+
+/// Relative size below which remainder coefficients of inexact types are
+/// treated as zero by [`Polynomial::gcd`].
+pub(crate) const GCD_TOLERANCE: f64 = 1e-9;
+
+impl<T: Field> Polynomial<T> {
+    /// Returns the monic greatest common divisor of `self` and `other`.
+    ///
+    /// Exact coefficient types use the subresultant polynomial remainder
+    /// sequence, which keeps intermediate coefficients small. Floating-point
+    /// types use the Euclidean algorithm, treating remainder coefficients
+    /// below a relative tolerance of `1e-9` as zero; see
+    /// [`Polynomial::gcd_with_tolerance`].
+    ///
+    /// The gcd of two zero polynomials is zero. Negative powers of `x` are
+    /// units among Laurent polynomials, so inputs with negative degrees are
+    /// first multiplied by the power of `x` that makes their lowest degree
+    /// zero.
+    pub fn gcd(&self, other: &Polynomial<T>) -> Polynomial<T> {
+        self.gcd_with_tolerance(other, GCD_TOLERANCE)
+    }
+
+    /// Like [`Polynomial::gcd`], treating remainder coefficients of inexact
+    /// types whose magnitude is at most `tolerance` times the largest
+    /// coefficient of the dividend as zero. The tolerance is ignored for
+    /// exact types.
+    pub fn gcd_with_tolerance(&self, other: &Polynomial<T>, tolerance: f64) -> Polynomial<T> {
+        let (mut a, mut b) = (
+            self.without_negative_powers(),
+            other.without_negative_powers(),
+        );
+        if a.degree() < b.degree() {
+            std::mem::swap(&mut a, &mut b);
+        }
+        if b.is_empty() {
+            return a.monic();
+        }
+
+        if T::EXACT {
+            subresultant_prs(a, b).monic()
+        } else {
+            while !b.is_empty() {
+                let (_, remainder) = euclidean_step(&a, &b, tolerance);
+                a = b;
+                b = remainder;
+            }
+            a.monic()
+        }
+    }
+
+    /// Returns the monic least common multiple, or zero if either input is
+    /// zero.
+    pub fn lcm(&self, other: &Polynomial<T>) -> Polynomial<T> {
+        if self.is_empty() || other.is_empty() {
+            return Self::zero();
+        }
+
+        let gcd = self.gcd(other);
+        let (quotient, _) = self
+            .without_negative_powers()
+            .div_rem(&gcd)
+            .expect("the gcd of non-zero polynomials is non-zero");
+        (quotient * other.without_negative_powers()).monic()
+    }
+
+    /// Returns `(g, s, t)` where `g` is the monic gcd and `s` and `t` are
+    /// Bézout coefficients with `s * self + t * other = g`.
+    ///
+    /// Uses the extended Euclidean algorithm with the tolerance of
+    /// [`Polynomial::gcd`] for inexact types. Inputs with negative powers are
+    /// normalized as described there, and the identity holds for the
+    /// normalized inputs.
+    pub fn extended_gcd(
+        &self,
+        other: &Polynomial<T>,
+    ) -> (Polynomial<T>, Polynomial<T>, Polynomial<T>) {
+        let (mut a, mut b) = (
+            self.without_negative_powers(),
+            other.without_negative_powers(),
+        );
+        let (mut s, mut next_s) = (Self::constant(T::one()), Self::zero());
+        let (mut t, mut next_t) = (Self::zero(), Self::constant(T::one()));
+
+        while !b.is_empty() {
+            let (quotient, remainder) = euclidean_step(&a, &b, GCD_TOLERANCE);
+
+            let s_update = &s - &quotient * &next_s;
+            let t_update = &t - &quotient * &next_t;
+            (a, b) = (b, remainder);
+            (s, next_s) = (next_s, s_update);
+            (t, next_t) = (next_t, t_update);
+        }
+
+        if a.is_empty() {
+            return (a, s, t);
+        }
+        let scale = T::one() / a.leading_coefficient();
+        (a * scale.clone(), s * scale.clone(), t * scale)
+    }
+
+    /// Returns the square-free part `p / gcd(p, p')`, the monic product of
+    /// the distinct irreducible factors.
+    pub fn square_free_part(&self) -> Polynomial<T> {
+        let p = self.without_negative_powers();
+        if p.is_empty() {
+            return p;
+        }
+
+        let (quotient, _) = p
+            .div_rem(&p.gcd(&p.differentiate()))
+            .expect("the gcd of a non-zero polynomial is non-zero");
+        quotient.monic()
+    }
+
+    /// Splits the polynomial into pairwise coprime, square-free, monic
+    /// factors `f` with multiplicities `m`, so that it equals its leading
+    /// coefficient times the product of every `f^m`.
+    ///
+    /// Uses Yun's algorithm, which only needs gcds with derivatives.
+    /// Factors are listed by increasing multiplicity; constants and the zero
+    /// polynomial have no factors.
+    pub fn square_free_decomposition(&self) -> Vec<(Polynomial<T>, usize)> {
+        let p = self.without_negative_powers();
+        let mut factors = Vec::new();
+        if p.degree().is_none_or(|d| d == 0) {
+            return factors;
+        }
+
+        let derivative = p.differentiate();
+        let a = p.gcd(&derivative);
+        let mut b = exact_quotient(&p, &a);
+        let mut c = exact_quotient(&derivative, &a);
+        let mut d = &c - &b.differentiate();
+
+        let mut multiplicity = 1;
+        while b.degree().is_some_and(|degree| degree > 0) {
+            let factor = b.gcd(&d);
+            b = exact_quotient(&b, &factor);
+            c = exact_quotient(&d, &factor);
+            d = &c - &b.differentiate();
+
+            if factor.degree().is_some_and(|degree| degree > 0) {
+                factors.push((factor, multiplicity));
+            }
+            multiplicity += 1;
+        }
+
+        factors
+    }
+
+    /// Scales the polynomial so its leading coefficient is one.
+    fn monic(self) -> Polynomial<T> {
+        if self.is_empty() {
+            return self;
+        }
+        let lead = self.leading_coefficient();
+        self / lead
+    }
+}
+
+impl<T: Coefficient> Polynomial<T> {
+    /// Multiplies by the power of `x` that makes the lowest degree zero, if
+    /// it is negative.
+    pub(crate) fn without_negative_powers(&self) -> Polynomial<T> {
+        match self.degrees.first() {
+            Some(&low) if low < 0 => {
+                Self::from_sorted_terms(self.terms().map(|(d, c)| (d - low, c)))
+            }
+            _ => self.clone(),
+        }
+    }
+}
+
+/// Implements `gcd` for integer coefficient types, which have no exact
+/// division and so cannot share the [`Field`] implementation.
+macro_rules! integer_gcd {
+    ($($t:ty),*) => {$(
+        impl Polynomial<$t> {
+            /// Returns the greatest common divisor over the integers: the gcd
+            /// of the contents times the primitive part of the last
+            /// subresultant, with a positive leading coefficient.
+            ///
+            /// Negative powers are normalized as for
+            /// [`Polynomial::gcd`]. Intermediate coefficients
+            /// can grow beyond those of the inputs, so fixed-width types may
+            /// overflow where [`BigInt`] cannot.
+            pub fn gcd(&self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                primitive_gcd(self, other)
+            }
+        }
+    )*};
+}
+
+integer_gcd!(i32, i64, i128, BigInt);
+
+/// Returns the non-negative gcd of two integers.
+fn integer_gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: Coefficient + PartialOrd + Rem<Output = T>,
+{
+    while !b.is_zero() {
+        (a, b) = (b.clone(), a % b);
+    }
+    if a < T::zero() {
+        -a
+    } else {
+        a
+    }
+}
+
+/// Splits `p` into its content, the gcd of its coefficients, and its
+/// primitive part with a positive leading coefficient.
+fn primitive_part<T>(p: &Polynomial<T>) -> (T, Polynomial<T>)
+where
+    T: Coefficient + PartialOrd + Rem<Output = T>,
+{
+    let content = p
+        .coefficients()
+        .iter()
+        .cloned()
+        .fold(T::zero(), integer_gcd);
+    if content.is_zero() {
+        return (content, p.clone());
+    }
+
+    let primitive = p / content.clone();
+    if primitive.leading_coefficient() < T::zero() {
+        (content, -primitive)
+    } else {
+        (content, primitive)
+    }
+}
+
+fn primitive_gcd<T>(a: &Polynomial<T>, b: &Polynomial<T>) -> Polynomial<T>
+where
+    T: Coefficient + PartialOrd + Rem<Output = T>,
+{
+    let (mut a, mut b) = (a.without_negative_powers(), b.without_negative_powers());
+    if a.degree() < b.degree() {
+        std::mem::swap(&mut a, &mut b);
+    }
+    let (a_content, a) = primitive_part(&a);
+    if b.is_empty() {
+        return a * a_content;
+    }
+    let (b_content, b) = primitive_part(&b);
+
+    let (_, gcd) = primitive_part(&subresultant_prs(a, b));
+    gcd * integer_gcd(a_content, b_content)
+}
+
+/// Returns `a / b` for a `b` known to divide `a`, discarding the rounding
+/// noise left in the remainder.
+fn exact_quotient<T: Field>(a: &Polynomial<T>, b: &Polynomial<T>) -> Polynomial<T> {
+    a.div_rem(b).expect("divisor is non-zero").0
+}
+
+/// Divides `a` by `b`, dropping remainder coefficients of inexact types that
+/// are at most `tolerance` times the largest coefficient of `a`.
+pub(crate) fn euclidean_step<T: Field>(
+    a: &Polynomial<T>,
+    b: &Polynomial<T>,
+    tolerance: f64,
+) -> (Polynomial<T>, Polynomial<T>) {
+    let (quotient, mut remainder) = a.div_rem(b).expect("divisor is non-zero");
+    if !T::EXACT {
+        let scale = a
+            .coefficients()
+            .iter()
+            .map(T::magnitude)
+            .fold(0.0, f64::max);
+        remainder.trim(tolerance * scale);
+    }
+    (quotient, remainder)
+}
+
+/// Returns the remainder of `lc(b)^(δ + 1) a` divided by `b`, where `δ` is
+/// the difference of their degrees, which needs no division.
+fn pseudo_remainder<T: Coefficient>(a: &Polynomial<T>, b: &Polynomial<T>) -> Polynomial<T> {
+    let (lead_degree, lead) = b.terms().next_back().expect("divisor is non-zero");
+    let delta = a.degree().unwrap_or(0) - lead_degree;
+
+    let mut remainder = a.clone();
+    let mut steps = 0;
+    while let Some(degree) = remainder.degree().filter(|&d| d >= lead_degree) {
+        let factor = Polynomial::monomial(remainder.leading_coefficient(), degree - lead_degree);
+        remainder = remainder * lead.clone() - &factor * b;
+        steps += 1;
+    }
+
+    // Each step multiplied by the lead once; make up for skipped degrees.
+    remainder * powi(&lead, delta + 1 - steps)
+}
+
+/// Runs the subresultant remainder sequence on `a` and `b`, where `b` is
+/// non-zero and of degree at most that of `a`, and returns the last non-zero
+/// remainder, a constant if they are coprime.
+///
+/// Every division in the sequence is exact, so this works over integer
+/// coefficients as well as fields, and keeps intermediate coefficients small.
+fn subresultant_prs<T: Coefficient>(mut a: Polynomial<T>, mut b: Polynomial<T>) -> Polynomial<T> {
+    let mut g = T::one();
+    let mut h = T::one();
+
+    loop {
+        let delta = a.degree().unwrap_or(0) - b.degree().unwrap_or(0);
+        let remainder = pseudo_remainder(&a, &b);
+        if remainder.is_empty() {
+            return b;
+        }
+        if remainder.degree() == Some(0) {
+            return remainder;
+        }
+
+        a = b;
+        b = remainder / (g.clone() * powi(&h, delta));
+        g = a.leading_coefficient();
+        // h = g^δ h^(1 - δ), kept free of integer reciprocals.
+        h = match delta {
+            0 => h,
+            _ => powi(&g, delta) / powi(&h, delta - 1),
+        };
+    }
+}
@@ -0,0 +1,46 @@
+use crate::coefficient::Field;
+use crate::error::NumericalError;
+use crate::polynomial::Polynomial;
+
+impl<T: Field> Polynomial<T> {
+    /// Returns the antiderivative whose constant term is `constant`.
+    ///
+    /// Fails with [`NumericalError::InvalidDomain`] if the polynomial has an
+    /// `x^-1` term, whose antiderivative `ln|x|` is not a polynomial.
+    pub fn integrate(&self, constant: T) -> Result<Polynomial<T>, NumericalError> {
+        let mut terms: Vec<(i32, T)> = Vec::with_capacity(self.len() + 1);
+
+        for (degree, coefficient) in self.terms() {
+            if degree == -1 {
+                return Err(NumericalError::InvalidDomain(
+                    "the antiderivative of an x^-1 term is not a polynomial".to_string(),
+                ));
+            }
+            terms.push((degree + 1, coefficient / T::from_i32(degree + 1)));
+        }
+        terms.push((0, constant));
+
+        Ok(Polynomial::from_terms(terms))
+    }
+}
+
+impl<T: Field + PartialOrd> Polynomial<T> {
+    /// Integrates the polynomial over `[a, b]`.
+    ///
+    /// Fails with [`NumericalError::InvalidDomain`] if the polynomial has
+    /// negative powers of `x` and the interval contains zero.
+    pub fn definite_integral(&self, a: T, b: T) -> Result<T, NumericalError> {
+        let antiderivative = self.integrate(T::zero())?;
+
+        let zero = T::zero();
+        let singular = self.degrees.first().is_some_and(|&d| d < 0);
+        let contains_zero = (a <= zero && b >= zero) || (b <= zero && a >= zero);
+        if singular && contains_zero {
+            return Err(NumericalError::InvalidDomain(
+                "the integrand is singular at x = 0 inside the interval".to_string(),
+            ));
+        }
+
+        Ok(antiderivative.compute(b) - antiderivative.compute(a))
+    }
+}
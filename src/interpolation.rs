@@ -0,0 +1,266 @@
+use crate::error::NumericalError;
+use crate::polynomial::Polynomial;
+
+fn repeated_node(x: f64) -> NumericalError {
+    NumericalError::InvalidDomain(format!("interpolation node {} is repeated", x))
+}
+
+fn check_lengths(xs: &[f64], ys: &[f64]) -> Result<(), NumericalError> {
+    if xs.len() != ys.len() {
+        return Err(NumericalError::LengthMismatch {
+            left: xs.len(),
+            right: ys.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Expands the Newton form `c0 + c1 (x - z0) + c2 (x - z0)(x - z1) + ...`
+/// into monomial form with Horner's scheme.
+fn newton_to_monomial(nodes: &[f64], coefficients: &[f64]) -> Polynomial {
+    let Some((&last, rest)) = coefficients.split_last() else {
+        return Polynomial::zero();
+    };
+
+    let mut dense = vec![last];
+    for (&c, &z) in rest.iter().zip(nodes).rev() {
+        // dense * (x - z) + c
+        dense.insert(0, 0.0);
+        for i in 0..dense.len() - 1 {
+            dense[i] -= z * dense[i + 1];
+        }
+        dense[0] += c;
+    }
+
+    Polynomial::from_coefficients(dense)
+}
+
+impl Polynomial {
+    /// Returns the polynomial of lowest degree through the samples
+    /// `(xs[i], ys[i])`, in monomial form.
+    ///
+    /// Built from Newton's divided differences. Fails with
+    /// [`NumericalError::InvalidDomain`] if a node is repeated. For
+    /// evaluation only, [`BarycentricInterpolant`] is more stable.
+    pub fn interpolate(xs: &[f64], ys: &[f64]) -> Result<Polynomial, NumericalError> {
+        Ok(NewtonInterpolant::from_points(xs, ys)?.to_polynomial())
+    }
+
+    /// Returns the Hermite interpolant, the polynomial of lowest degree
+    /// matching `values[i] = [p(xs[i]), p'(xs[i]), p''(xs[i]), ...]` at each
+    /// node. Nodes may prescribe different numbers of derivatives.
+    ///
+    /// Uses divided differences on the nodes repeated once per prescribed
+    /// value, where a difference over a repeated node becomes a scaled
+    /// derivative. Fails with [`NumericalError::InvalidDomain`] if a node is
+    /// repeated or has no values.
+    pub fn interpolate_hermite<V: AsRef<[f64]>>(
+        xs: &[f64],
+        values: &[V],
+    ) -> Result<Polynomial, NumericalError> {
+        if xs.len() != values.len() {
+            return Err(NumericalError::LengthMismatch {
+                left: xs.len(),
+                right: values.len(),
+            });
+        }
+
+        let mut nodes = Vec::new();
+        // The index in `xs` each repeated node came from.
+        let mut origins = Vec::new();
+        for (i, (&x, v)) in xs.iter().zip(values).enumerate() {
+            if xs[..i].contains(&x) {
+                return Err(repeated_node(x));
+            }
+            if v.as_ref().is_empty() {
+                return Err(NumericalError::InvalidDomain(format!(
+                    "interpolation node {} has no values",
+                    x
+                )));
+            }
+            nodes.extend(std::iter::repeat_n(x, v.as_ref().len()));
+            origins.extend(std::iter::repeat_n(i, v.as_ref().len()));
+        }
+
+        let mut table: Vec<f64> = origins.iter().map(|&i| values[i].as_ref()[0]).collect();
+        let mut factorial = 1.0;
+        for k in 1..nodes.len() {
+            factorial *= k as f64;
+            for i in (k..nodes.len()).rev() {
+                table[i] = if nodes[i] == nodes[i - k] {
+                    // All of `nodes[i - k..=i]` are the same point, and the
+                    // k-th difference there is the k-th derivative over k!.
+                    values[origins[i]].as_ref()[k] / factorial
+                } else {
+                    (table[i] - table[i - 1]) / (nodes[i] - nodes[i - k])
+                };
+            }
+        }
+
+        Ok(newton_to_monomial(&nodes, &table))
+    }
+}
+
+/// An interpolating polynomial in Newton form, to which points can be added
+/// one at a time in `O(n)` operations each.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NewtonInterpolant {
+    nodes: Vec<f64>,
+    coefficients: Vec<f64>,
+    /// `differences[i]` is the divided difference over `nodes[i..]`, the
+    /// last diagonal of the table, which is all that adding a point needs.
+    differences: Vec<f64>,
+}
+
+impl NewtonInterpolant {
+    /// An interpolant with no points, which is identically zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the interpolant through `(xs[i], ys[i])`.
+    pub fn from_points(xs: &[f64], ys: &[f64]) -> Result<Self, NumericalError> {
+        check_lengths(xs, ys)?;
+
+        let mut interpolant = Self::new();
+        for (&x, &y) in xs.iter().zip(ys) {
+            interpolant.push(x, y)?;
+        }
+        Ok(interpolant)
+    }
+
+    /// Adds the point `(x, y)`, raising the degree by one without touching
+    /// the existing coefficients.
+    ///
+    /// Fails with [`NumericalError::InvalidDomain`] if `x` is already a node.
+    pub fn push(&mut self, x: f64, y: f64) -> Result<(), NumericalError> {
+        if self.nodes.contains(&x) {
+            return Err(repeated_node(x));
+        }
+
+        let mut difference = y;
+        for (&node, previous) in self.nodes.iter().zip(&mut self.differences).rev() {
+            difference = (difference - *previous) / (x - node);
+            *previous = difference;
+        }
+
+        self.differences.push(y);
+        self.coefficients.push(difference);
+        self.nodes.push(x);
+        Ok(())
+    }
+
+    /// Number of interpolation points.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if no points have been added.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The interpolation nodes, in the order they were added.
+    pub fn nodes(&self) -> &[f64] {
+        &self.nodes
+    }
+
+    /// The divided differences `f[x0]`, `f[x0, x1]`, ..., which are the
+    /// coefficients of the Newton form.
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Evaluates the interpolant at `x` with nested multiplication.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .zip(&self.nodes)
+            .rev()
+            .fold(0.0, |acc, (&c, &node)| acc * (x - node) + c)
+    }
+
+    /// Converts to monomial form.
+    pub fn to_polynomial(&self) -> Polynomial {
+        newton_to_monomial(&self.nodes, &self.coefficients)
+    }
+}
+
+/// An interpolating polynomial in the second barycentric form
+///
+/// `p(x) = Σ w_j y_j / (x - x_j) / Σ w_j / (x - x_j)`,
+///
+/// which is backward stable for well-chosen nodes, such as Chebyshev
+/// points, and evaluates in `O(n)` after `O(n^2)` setup.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarycentricInterpolant {
+    nodes: Vec<f64>,
+    values: Vec<f64>,
+    weights: Vec<f64>,
+}
+
+impl BarycentricInterpolant {
+    /// Builds the interpolant through `(xs[i], ys[i])`.
+    ///
+    /// Fails with [`NumericalError::InvalidDomain`] if a node is repeated.
+    pub fn new(xs: &[f64], ys: &[f64]) -> Result<Self, NumericalError> {
+        check_lengths(xs, ys)?;
+
+        let mut weights = Vec::with_capacity(xs.len());
+        for (j, &xj) in xs.iter().enumerate() {
+            let mut product = 1.0;
+            for (k, &xk) in xs.iter().enumerate() {
+                if k != j {
+                    if xk == xj {
+                        return Err(repeated_node(xj));
+                    }
+                    product *= xj - xk;
+                }
+            }
+            weights.push(1.0 / product);
+        }
+
+        Ok(Self {
+            nodes: xs.to_vec(),
+            values: ys.to_vec(),
+            weights,
+        })
+    }
+
+    /// The barycentric weights `w_j = 1 / Π_(k≠j) (x_j - x_k)`.
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Evaluates the interpolant at `x`, returning the sample value exactly
+    /// at a node. With no points the interpolant is zero.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let mut numerator = 0.0;
+        let mut denominator = 0.0;
+
+        for ((&node, &y), &w) in self.nodes.iter().zip(&self.values).zip(&self.weights) {
+            let d = x - node;
+            if d == 0.0 {
+                return y;
+            }
+            numerator += w * y / d;
+            denominator += w / d;
+        }
+
+        if self.nodes.is_empty() {
+            0.0
+        } else {
+            numerator / denominator
+        }
+    }
+
+    /// Evaluates the interpolant at every point of `xs`.
+    pub fn evaluate_many(&self, xs: &[f64]) -> Vec<f64> {
+        xs.iter().map(|&x| self.evaluate(x)).collect()
+    }
+
+    /// Converts to monomial form, which can lose accuracy for many points.
+    pub fn to_polynomial(&self) -> Polynomial {
+        Polynomial::interpolate(&self.nodes, &self.values).expect("nodes were checked")
+    }
+}
@@ -0,0 +1,380 @@
+//! Dependency-free JSON, CSV and binary encodings of `Polynomial<f64>`.
+//!
+//! Every reader builds its result with [`Polynomial::from_terms`], so decoded
+//! polynomials are canonical exactly like those from [`Polynomial::new`].
+
+use std::fmt::Write;
+
+use crate::error::{NumericalError, ParseError, ParseErrorKind};
+use crate::polynomial::Polynomial;
+
+/// Leading bytes of the binary encoding.
+const MAGIC: &[u8; 4] = b"NPOL";
+const VERSION: u8 = 1;
+/// Bytes per encoded term: an `i32` degree and an `f64` coefficient.
+const TERM_SIZE: usize = 12;
+/// Deepest nesting of JSON arrays and objects the reader accepts, which
+/// keeps hostile input from overflowing the stack.
+const MAX_JSON_DEPTH: usize = 128;
+
+fn error(offset: usize, kind: ParseErrorKind) -> NumericalError {
+    ParseError { offset, kind }.into()
+}
+
+/// Converts a JSON number or CSV field to a degree, rejecting fractions and
+/// values outside the `i32` range.
+fn to_degree(value: f64, offset: usize) -> Result<i32, NumericalError> {
+    if value.fract() != 0.0 || value < i32::MIN as f64 || value > i32::MAX as f64 {
+        return Err(error(offset, ParseErrorKind::InvalidExponent));
+    }
+    Ok(value as i32)
+}
+
+/// The subset of JSON values the reader needs to tell apart.
+enum Json {
+    Number(f64),
+    Array(Vec<(usize, Json)>),
+    Object(Vec<(String, usize, Json)>),
+    Other,
+}
+
+/// A minimal recursive-descent JSON parser that records the byte offset of
+/// every value for error reporting.
+struct JsonParser<'a> {
+    input: &'a str,
+    position: usize,
+    /// Number of arrays and objects currently open.
+    depth: usize,
+}
+
+impl JsonParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.position).copied()
+    }
+
+    /// Consumes and returns the next character.
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.input[self.position..].chars().next()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn unexpected(&self) -> NumericalError {
+        match self.input[self.position..].chars().next() {
+            Some(c) => error(self.position, ParseErrorKind::UnexpectedCharacter(c)),
+            None => error(self.position, ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), NumericalError> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(self.unexpected());
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn literal(&mut self, text: &str) -> Result<Json, NumericalError> {
+        if !self.input[self.position..].starts_with(text) {
+            return Err(self.unexpected());
+        }
+        self.position += text.len();
+        Ok(Json::Other)
+    }
+
+    fn string(&mut self) -> Result<String, NumericalError> {
+        self.expect(b'"')?;
+        let mut text = String::new();
+        loop {
+            match self.next_char() {
+                None => return Err(self.unexpected()),
+                Some('"') => return Ok(text),
+                Some('\\') => {
+                    // Escapes are kept verbatim; keys of interest never use them.
+                    text.push('\\');
+                    text.push(self.next_char().ok_or_else(|| self.unexpected())?);
+                }
+                Some(c) => text.push(c),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, NumericalError> {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|b| b.is_ascii_digit() || b"+-.eE".contains(&b))
+        {
+            self.position += 1;
+        }
+        self.input[start..self.position]
+            .parse()
+            .ok()
+            .map(Json::Number)
+            .ok_or_else(|| error(start, ParseErrorKind::InvalidNumber))
+    }
+
+    fn value(&mut self) -> Result<(usize, Json), NumericalError> {
+        self.skip_whitespace();
+        let start = self.position;
+        if matches!(self.peek(), Some(b'{' | b'[')) {
+            if self.depth == MAX_JSON_DEPTH {
+                return Err(error(
+                    start,
+                    ParseErrorKind::InvalidFormat("nested too deeply"),
+                ));
+            }
+            self.depth += 1;
+        }
+
+        let value = match self.peek() {
+            Some(b'{') => {
+                self.position += 1;
+                let mut members = Vec::new();
+                self.skip_whitespace();
+                if self.peek() == Some(b'}') {
+                    self.position += 1;
+                } else {
+                    loop {
+                        self.skip_whitespace();
+                        let key = self.string()?;
+                        self.expect(b':')?;
+                        let (offset, value) = self.value()?;
+                        members.push((key, offset, value));
+                        self.skip_whitespace();
+                        match self.peek() {
+                            Some(b',') => self.position += 1,
+                            Some(b'}') => {
+                                self.position += 1;
+                                break;
+                            }
+                            _ => return Err(self.unexpected()),
+                        }
+                    }
+                }
+                self.depth -= 1;
+                Json::Object(members)
+            }
+            Some(b'[') => {
+                self.position += 1;
+                let mut elements = Vec::new();
+                self.skip_whitespace();
+                if self.peek() == Some(b']') {
+                    self.position += 1;
+                } else {
+                    loop {
+                        elements.push(self.value()?);
+                        self.skip_whitespace();
+                        match self.peek() {
+                            Some(b',') => self.position += 1,
+                            Some(b']') => {
+                                self.position += 1;
+                                break;
+                            }
+                            _ => return Err(self.unexpected()),
+                        }
+                    }
+                }
+                self.depth -= 1;
+                Json::Array(elements)
+            }
+            Some(b'"') => {
+                self.string()?;
+                Json::Other
+            }
+            Some(b't') => self.literal("true")?,
+            Some(b'f') => self.literal("false")?,
+            Some(b'n') => self.literal("null")?,
+            Some(b'-' | b'0'..=b'9') => self.number()?,
+            _ => return Err(self.unexpected()),
+        };
+        Ok((start, value))
+    }
+}
+
+impl Polynomial {
+    /// Encodes the polynomial as `{"terms": [[degree, coefficient], ...]}`
+    /// in ascending order of degree.
+    ///
+    /// Non-finite coefficients have no JSON representation and are written
+    /// as `null`, which [`Polynomial::from_json`] rejects.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\"terms\": [");
+        for (i, (degree, coefficient)) in self.terms().enumerate() {
+            if i > 0 {
+                json.push_str(", ");
+            }
+            if coefficient.is_finite() {
+                let _ = write!(json, "[{}, {:?}]", degree, coefficient);
+            } else {
+                let _ = write!(json, "[{}, null]", degree);
+            }
+        }
+        json.push_str("]}");
+        json
+    }
+
+    /// Decodes the JSON produced by [`Polynomial::to_json`]. Other members
+    /// of the top-level object are ignored.
+    pub fn from_json(json: &str) -> Result<Polynomial, NumericalError> {
+        let mut parser = JsonParser {
+            input: json,
+            position: 0,
+            depth: 0,
+        };
+        let (_, document) = parser.value()?;
+        parser.skip_whitespace();
+        if parser.position < json.len() {
+            return Err(parser.unexpected());
+        }
+
+        let Json::Object(members) = document else {
+            return Err(error(
+                0,
+                ParseErrorKind::InvalidFormat("expected an object"),
+            ));
+        };
+        let Some((_, offset, terms)) = members.into_iter().find(|(key, _, _)| key == "terms")
+        else {
+            return Err(error(0, ParseErrorKind::InvalidFormat("missing \"terms\"")));
+        };
+        let Json::Array(terms) = terms else {
+            return Err(error(
+                offset,
+                ParseErrorKind::InvalidFormat("\"terms\" must be an array"),
+            ));
+        };
+
+        let mut parsed = Vec::with_capacity(terms.len());
+        for (offset, term) in terms {
+            let pair = match term {
+                Json::Array(pair) => pair,
+                _ => Vec::new(),
+            };
+            match pair.as_slice() {
+                [(degree_offset, Json::Number(degree)), (_, Json::Number(coefficient))] => {
+                    parsed.push((to_degree(*degree, *degree_offset)?, *coefficient));
+                }
+                _ => {
+                    return Err(error(
+                        offset,
+                        ParseErrorKind::InvalidFormat("each term must be [degree, coefficient]"),
+                    ))
+                }
+            }
+        }
+
+        Ok(Polynomial::from_terms(parsed))
+    }
+
+    /// Encodes the polynomial as CSV with a `degree,coefficient` header and
+    /// one row per term in ascending order of degree.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("degree,coefficient\n");
+        for (degree, coefficient) in self.terms() {
+            let _ = writeln!(csv, "{},{:?}", degree, coefficient);
+        }
+        csv
+    }
+
+    /// Decodes `degree,coefficient` rows. A header row matching the one
+    /// written by [`Polynomial::to_csv`], blank lines and surrounding
+    /// whitespace are allowed.
+    pub fn from_csv(csv: &str) -> Result<Polynomial, NumericalError> {
+        let mut terms = Vec::new();
+        let mut line_start = 0;
+
+        for (index, line) in csv.split('\n').enumerate() {
+            let offset = line_start;
+            line_start += line.len() + 1;
+
+            let line = line.trim();
+            if line.is_empty() || (index == 0 && line.eq_ignore_ascii_case("degree,coefficient")) {
+                continue;
+            }
+
+            let Some((degree, coefficient)) = line.split_once(',') else {
+                return Err(error(
+                    offset,
+                    ParseErrorKind::InvalidFormat("expected degree,coefficient"),
+                ));
+            };
+            let coefficient_offset = offset + degree.len() + 1;
+
+            let degree: f64 = degree
+                .trim()
+                .parse()
+                .map_err(|_| error(offset, ParseErrorKind::InvalidNumber))?;
+            let coefficient: f64 = coefficient
+                .trim()
+                .parse()
+                .map_err(|_| error(coefficient_offset, ParseErrorKind::InvalidNumber))?;
+
+            terms.push((to_degree(degree, offset)?, coefficient));
+        }
+
+        Ok(Polynomial::from_terms(terms))
+    }
+
+    /// Encodes the polynomial in a compact little-endian binary format: the
+    /// magic bytes `NPOL`, a version byte, a `u32` term count and then an
+    /// `i32` degree and `f64` coefficient per term.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(MAGIC.len() + 5 + TERM_SIZE * self.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&(self.len() as u32).to_le_bytes());
+        for (degree, coefficient) in self.terms() {
+            bytes.extend_from_slice(&degree.to_le_bytes());
+            bytes.extend_from_slice(&coefficient.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decodes the format written by [`Polynomial::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Polynomial, NumericalError> {
+        let header = MAGIC.len() + 5;
+        if bytes.len() < header {
+            return Err(error(bytes.len(), ParseErrorKind::UnexpectedEnd));
+        }
+        if &bytes[..MAGIC.len()] != MAGIC {
+            return Err(error(0, ParseErrorKind::InvalidFormat("bad magic bytes")));
+        }
+        if bytes[MAGIC.len()] != VERSION {
+            return Err(error(
+                MAGIC.len(),
+                ParseErrorKind::InvalidFormat("unsupported version"),
+            ));
+        }
+
+        let count = u32::from_le_bytes(bytes[MAGIC.len() + 1..header].try_into().unwrap()) as usize;
+        let body = &bytes[header..];
+        if body.len() != count.saturating_mul(TERM_SIZE) {
+            let offset = header + body.len().min(count.saturating_mul(TERM_SIZE));
+            return Err(error(
+                offset,
+                if body.len() < count.saturating_mul(TERM_SIZE) {
+                    ParseErrorKind::UnexpectedEnd
+                } else {
+                    ParseErrorKind::InvalidFormat("trailing bytes")
+                },
+            ));
+        }
+
+        let terms = body.chunks_exact(TERM_SIZE).map(|chunk| {
+            (
+                i32::from_le_bytes(chunk[..4].try_into().unwrap()),
+                f64::from_le_bytes(chunk[4..].try_into().unwrap()),
+            )
+        });
+
+        Ok(Polynomial::from_terms(terms))
+    }
+}
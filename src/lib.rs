@@ -0,0 +1,44 @@
+//! Numerical tools built around a sparse [`Polynomial`] type.
+
+mod bigint;
+mod coefficient;
+mod complex;
+mod composition;
+mod division;
+mod dual;
+mod error;
+mod evaluation;
+mod fitting;
+mod format;
+mod gcd;
+mod integration;
+mod interpolation;
+mod io;
+mod matrix;
+mod ops;
+mod orthogonal;
+mod parse;
+mod polynomial;
+mod rational;
+mod ring;
+mod roots;
+#[cfg(feature = "serde")]
+mod serde_impl;
+pub mod solvers;
+mod sturm;
+mod taylor;
+
+pub use bigint::BigInt;
+pub use coefficient::{Coefficient, Field};
+pub use complex::Complex64;
+pub use dual::Dual;
+pub use error::{NumericalError, ParseError, ParseErrorKind};
+pub use fitting::{Fit, FitBasis, FitOptions};
+pub use format::{Formatted, Notation};
+pub use interpolation::{BarycentricInterpolant, NewtonInterpolant};
+pub use matrix::Matrix;
+pub use orthogonal::OrthogonalFamily;
+pub use polynomial::Polynomial;
+pub use rational::Rational;
+pub use ring::Ring;
+pub use roots::Root;
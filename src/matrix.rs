@@ -0,0 +1,361 @@
+use std::ops::{Add, Index, IndexMut, Mul};
+
+use crate::error::NumericalError;
+use crate::ring::Ring;
+
+const MAX_JACOBI_SWEEPS: usize = 60;
+
+/// A dense, row-major matrix of `f64`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    /// Builds a `rows x cols` matrix from row-major `data`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data.len() != rows * cols`.
+    pub fn new(rows: usize, cols: usize, data: Vec<f64>) -> Self {
+        assert_eq!(data.len(), rows * cols, "matrix data has the wrong length");
+        Self { rows, cols, data }
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self::new(rows, cols, vec![0.0; rows * cols])
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut identity = Self::zeros(n, n);
+        for i in 0..n {
+            identity[(i, i)] = 1.0;
+        }
+        identity
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The entries in row-major order.
+    pub fn data(&self) -> &[f64] {
+        &self.data
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let mut transposed = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                transposed[(j, i)] = self[(i, j)];
+            }
+        }
+        transposed
+    }
+
+    /// Inverts a square matrix by Gauss–Jordan elimination with partial
+    /// pivoting.
+    ///
+    /// Fails with [`NumericalError::LengthMismatch`] if the matrix is not
+    /// square and [`NumericalError::SingularMatrix`] if it is singular.
+    pub fn inverse(&self) -> Result<Matrix, NumericalError> {
+        if !self.is_square() {
+            return Err(NumericalError::LengthMismatch {
+                left: self.rows,
+                right: self.cols,
+            });
+        }
+
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut inverse = Self::identity(n);
+
+        for col in 0..n {
+            let pivot = (col..n)
+                .max_by(|&i, &j| a[(i, col)].abs().total_cmp(&a[(j, col)].abs()))
+                .unwrap_or(col);
+            if a[(pivot, col)] == 0.0 {
+                return Err(NumericalError::SingularMatrix);
+            }
+            a.swap_rows(col, pivot);
+            inverse.swap_rows(col, pivot);
+
+            let scale = 1.0 / a[(col, col)];
+            for j in 0..n {
+                a[(col, j)] *= scale;
+                inverse[(col, j)] *= scale;
+            }
+
+            for row in (0..n).filter(|&row| row != col) {
+                let factor = a[(row, col)];
+                if factor == 0.0 {
+                    continue;
+                }
+                for j in 0..n {
+                    a[(row, j)] -= factor * a[(col, j)];
+                    inverse[(row, j)] -= factor * inverse[(col, j)];
+                }
+            }
+        }
+
+        Ok(inverse)
+    }
+
+    /// Solves the least-squares problem `min |A x - b|` by Householder QR,
+    /// without forming the normal equations.
+    ///
+    /// Fails with [`NumericalError::LengthMismatch`] if `b` does not have one
+    /// entry per row, [`NumericalError::InvalidDomain`] if there are fewer rows
+    /// than columns and [`NumericalError::SingularMatrix`] if the columns are
+    /// linearly dependent.
+    pub fn solve_least_squares(&self, b: &[f64]) -> Result<Vec<f64>, NumericalError> {
+        self.least_squares_qr(b).map(|(x, _)| x)
+    }
+
+    /// Like [`Matrix::solve_least_squares`], also returning the `n x n`
+    /// triangular factor `R` of `A = QR`, from which `(AᵀA)⁻¹ = R⁻¹R⁻ᵀ`
+    /// follows.
+    pub(crate) fn least_squares_qr(&self, b: &[f64]) -> Result<(Vec<f64>, Matrix), NumericalError> {
+        let (m, n) = (self.rows, self.cols);
+        if b.len() != m {
+            return Err(NumericalError::LengthMismatch {
+                left: m,
+                right: b.len(),
+            });
+        }
+        if m < n {
+            return Err(NumericalError::InvalidDomain(
+                "least squares needs at least as many rows as columns".to_string(),
+            ));
+        }
+
+        let mut a = self.clone();
+        let mut b = b.to_vec();
+
+        for k in 0..n {
+            let norm = (k..m).map(|i| a[(i, k)] * a[(i, k)]).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                return Err(NumericalError::SingularMatrix);
+            }
+
+            // Reflect column k onto -sign(a_kk) * norm * e_k.
+            let alpha = -norm.copysign(a[(k, k)]);
+            let mut v: Vec<f64> = (k..m).map(|i| a[(i, k)]).collect();
+            v[0] -= alpha;
+            let v_norm_squared: f64 = v.iter().map(|x| x * x).sum();
+
+            for j in k..n {
+                let dot: f64 = v.iter().enumerate().map(|(i, vi)| vi * a[(k + i, j)]).sum();
+                let factor = 2.0 * dot / v_norm_squared;
+                for (i, vi) in v.iter().enumerate() {
+                    a[(k + i, j)] -= factor * vi;
+                }
+            }
+
+            let dot: f64 = v.iter().enumerate().map(|(i, vi)| vi * b[k + i]).sum();
+            let factor = 2.0 * dot / v_norm_squared;
+            for (i, vi) in v.iter().enumerate() {
+                b[k + i] -= factor * vi;
+            }
+        }
+
+        let scale = (0..n).map(|k| a[(k, k)].abs()).fold(0.0, f64::max);
+        let mut x = vec![0.0; n];
+        for k in (0..n).rev() {
+            if a[(k, k)].abs() <= n as f64 * f64::EPSILON * scale {
+                return Err(NumericalError::SingularMatrix);
+            }
+            let sum: f64 = (k + 1..n).map(|j| a[(k, j)] * x[j]).sum();
+            x[k] = (b[k] - sum) / a[(k, k)];
+        }
+
+        let mut r = Matrix::zeros(n, n);
+        for i in 0..n {
+            for j in i..n {
+                r[(i, j)] = a[(i, j)];
+            }
+        }
+
+        Ok((x, r))
+    }
+
+    /// Returns the singular values in descending order, computed by
+    /// one-sided Jacobi rotations, which find even tiny singular values to
+    /// high relative accuracy.
+    pub fn singular_values(&self) -> Vec<f64> {
+        // Rotations orthogonalize the columns, so work with the taller shape.
+        let mut u = if self.rows < self.cols {
+            self.transpose()
+        } else {
+            self.clone()
+        };
+        let (m, n) = (u.rows, u.cols);
+
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let mut rotated = false;
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                    for i in 0..m {
+                        alpha += u[(i, p)] * u[(i, p)];
+                        beta += u[(i, q)] * u[(i, q)];
+                        gamma += u[(i, p)] * u[(i, q)];
+                    }
+                    if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = 1.0_f64.copysign(zeta) / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                    let c = 1.0 / (1.0 + t * t).sqrt();
+                    let s = c * t;
+                    for i in 0..m {
+                        let (up, uq) = (u[(i, p)], u[(i, q)]);
+                        u[(i, p)] = c * up - s * uq;
+                        u[(i, q)] = s * up + c * uq;
+                    }
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut values: Vec<f64> = (0..n)
+            .map(|j| (0..m).map(|i| u[(i, j)] * u[(i, j)]).sum::<f64>().sqrt())
+            .collect();
+        values.sort_by(|a, b| b.total_cmp(a));
+        values
+    }
+
+    /// Returns the 2-norm condition number, the ratio of the largest to the
+    /// smallest singular value, which is infinite for rank-deficient
+    /// matrices.
+    pub fn condition_number(&self) -> f64 {
+        let values = self.singular_values();
+        match (values.first(), values.last()) {
+            (Some(&largest), Some(&smallest)) if smallest > 0.0 => largest / smallest,
+            (Some(_), Some(_)) => f64::INFINITY,
+            _ => 1.0,
+        }
+    }
+
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        if i == j {
+            return;
+        }
+        for k in 0..self.cols {
+            self.data.swap(i * self.cols + k, j * self.cols + k);
+        }
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl Add for &Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: &Matrix) -> Matrix {
+        assert_eq!(
+            (self.rows, self.cols),
+            (rhs.rows, rhs.cols),
+            "matrix dimensions do not match"
+        );
+        Matrix::new(
+            self.rows,
+            self.cols,
+            self.data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(a, b)| a + b)
+                .collect(),
+        )
+    }
+}
+
+impl Add for Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: Matrix) -> Matrix {
+        &self + &rhs
+    }
+}
+
+impl Mul for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        assert_eq!(self.cols, rhs.rows, "matrix dimensions do not match");
+
+        let mut product = Matrix::zeros(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                if a == 0.0 {
+                    continue;
+                }
+                for j in 0..rhs.cols {
+                    product[(i, j)] += a * rhs[(k, j)];
+                }
+            }
+        }
+        product
+    }
+}
+
+impl Mul for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: Matrix) -> Matrix {
+        &self * &rhs
+    }
+}
+
+impl Ring for Matrix {
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    fn one_like(&self) -> Self {
+        assert!(self.is_square(), "matrix is not square");
+        Self::identity(self.rows)
+    }
+
+    fn scale(&self, factor: f64) -> Self {
+        Self::new(
+            self.rows,
+            self.cols,
+            self.data.iter().map(|a| a * factor).collect(),
+        )
+    }
+
+    /// The inverse, or a matrix of NaNs if `self` is singular.
+    fn recip(&self) -> Self {
+        self.inverse()
+            .unwrap_or_else(|_| Self::new(self.rows, self.cols, vec![f64::NAN; self.data.len()]))
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::bigint::BigInt;
+use crate::coefficient::{Coefficient, Field};
+use crate::complex::Complex64;
+use crate::polynomial::Polynomial;
+use crate::rational::Rational;
+
+/// Merges the sorted terms of `a` and `b`, combining coefficients of
+/// matching degrees with `combine` and mapping unmatched terms of `b` with
+/// `map_rhs`.
+fn merge<T: Coefficient>(
+    a: &Polynomial<T>,
+    b: &Polynomial<T>,
+    combine: fn(T, T) -> T,
+    map_rhs: fn(T) -> T,
+) -> Polynomial<T> {
+    let mut terms = Vec::with_capacity(a.len() + b.len());
+
+    let mut lhs = a.terms().peekable();
+    let mut rhs = b.terms().peekable();
+
+    loop {
+        let term = match (lhs.peek(), rhs.peek()) {
+            (Some((da, _)), Some((db, _))) => {
+                if da < db {
+                    lhs.next().unwrap()
+                } else if db < da {
+                    let (d, c) = rhs.next().unwrap();
+                    (d, map_rhs(c))
+                } else {
+                    let (d, ca) = lhs.next().unwrap();
+                    let (_, cb) = rhs.next().unwrap();
+                    (d, combine(ca, cb))
+                }
+            }
+            (Some(_), None) => lhs.next().unwrap(),
+            (None, Some(_)) => {
+                let (d, c) = rhs.next().unwrap();
+                (d, map_rhs(c))
+            }
+            (None, None) => break,
+        };
+        terms.push(term);
+    }
+
+    Polynomial::from_sorted_terms(terms)
+}
+
+/// Multiplies term by term, summing the coefficients of equal degrees.
+///
+/// # Panics
+///
+/// Panics if a product degree overflows `i32`.
+fn multiply<T: Coefficient>(a: &Polynomial<T>, b: &Polynomial<T>) -> Polynomial<T> {
+    let mut product: BTreeMap<i32, T> = BTreeMap::new();
+
+    for (da, ca) in a.terms() {
+        for (db, cb) in b.terms() {
+            let degree = da.checked_add(db).expect("degree overflows i32");
+            let entry = product.entry(degree).or_insert_with(T::zero);
+            *entry = entry.clone() + ca.clone() * cb;
+        }
+    }
+
+    Polynomial::from_sorted_terms(product)
+}
+
+fn scale<T: Coefficient>(p: &Polynomial<T>, factor: &T) -> Polynomial<T> {
+    Polynomial::from_sorted_terms(p.terms().map(|(d, c)| (d, c * factor.clone())))
+}
+
+/// Implements a binary operator for every owned/borrowed combination of
+/// `Polynomial` operands, forwarding to `$body` with both operands borrowed.
+macro_rules! forward_binop {
+    ($bound:ident, $imp:ident, $method:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl<T: $bound> $imp<&Polynomial<T>> for &Polynomial<T> {
+            type Output = Polynomial<T>;
+
+            fn $method(self, rhs: &Polynomial<T>) -> Polynomial<T> {
+                let ($a, $b) = (self, rhs);
+                $body
+            }
+        }
+
+        impl<T: $bound> $imp<Polynomial<T>> for &Polynomial<T> {
+            type Output = Polynomial<T>;
+
+            fn $method(self, rhs: Polynomial<T>) -> Polynomial<T> {
+                self.$method(&rhs)
+            }
+        }
+
+        impl<T: $bound> $imp<&Polynomial<T>> for Polynomial<T> {
+            type Output = Polynomial<T>;
+
+            fn $method(self, rhs: &Polynomial<T>) -> Polynomial<T> {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<T: $bound> $imp<Polynomial<T>> for Polynomial<T> {
+            type Output = Polynomial<T>;
+
+            fn $method(self, rhs: Polynomial<T>) -> Polynomial<T> {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+forward_binop!(Coefficient, Add, add, |a, b| merge(
+    a,
+    b,
+    |x, y| x + y,
+    |y| y
+));
+forward_binop!(Coefficient, Sub, sub, |a, b| merge(
+    a,
+    b,
+    |x, y| x - y,
+    |y| -y
+));
+forward_binop!(Coefficient, Mul, mul, |a, b| multiply(a, b));
+forward_binop!(Field, Div, div, |a, b| {
+    a.div_rem(b)
+        .expect("attempt to divide by the zero polynomial")
+        .0
+});
+forward_binop!(Field, Rem, rem, |a, b| {
+    a.div_rem(b)
+        .expect("attempt to calculate the remainder with a divisor of zero")
+        .1
+});
+
+/// Implements `$imp_assign` in terms of the matching binary operator.
+macro_rules! forward_assign {
+    ($bound:ident, $imp:ident, $method:ident, $op:tt) => {
+        impl<T: $bound> $imp<&Polynomial<T>> for Polynomial<T> {
+            fn $method(&mut self, rhs: &Polynomial<T>) {
+                *self = &*self $op rhs;
+            }
+        }
+
+        impl<T: $bound> $imp<Polynomial<T>> for Polynomial<T> {
+            fn $method(&mut self, rhs: Polynomial<T>) {
+                *self = &*self $op &rhs;
+            }
+        }
+    };
+}
+
+forward_assign!(Coefficient, AddAssign, add_assign, +);
+forward_assign!(Coefficient, SubAssign, sub_assign, -);
+forward_assign!(Coefficient, MulAssign, mul_assign, *);
+forward_assign!(Field, DivAssign, div_assign, /);
+forward_assign!(Field, RemAssign, rem_assign, %);
+
+impl<T: Coefficient> Neg for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Polynomial<T> {
+        -self.clone()
+    }
+}
+
+impl<T: Coefficient> Neg for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(mut self) -> Polynomial<T> {
+        self.coefficients = self.coefficients.into_iter().map(|c| -c).collect();
+        self
+    }
+}
+
+impl<T: Coefficient> Mul<T> for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: T) -> Polynomial<T> {
+        scale(self, &rhs)
+    }
+}
+
+impl<T: Coefficient> Mul<T> for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(mut self, rhs: T) -> Polynomial<T> {
+        self *= rhs;
+        self
+    }
+}
+
+/// Implements `scalar * polynomial` for concrete coefficient types, which
+/// cannot be done generically on the left-hand side.
+macro_rules! scalar_lhs_mul {
+    ($($t:ty),*) => {$(
+        impl Mul<Polynomial<$t>> for $t {
+            type Output = Polynomial<$t>;
+
+            fn mul(self, rhs: Polynomial<$t>) -> Polynomial<$t> {
+                rhs * self
+            }
+        }
+
+        impl Mul<&Polynomial<$t>> for $t {
+            type Output = Polynomial<$t>;
+
+            fn mul(self, rhs: &Polynomial<$t>) -> Polynomial<$t> {
+                rhs * self
+            }
+        }
+    )*};
+}
+
+scalar_lhs_mul!(f32, f64, i32, i64, i128, Complex64, BigInt, Rational);
+
+impl<T: Coefficient> Div<T> for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn div(self, rhs: T) -> Polynomial<T> {
+        Polynomial::from_sorted_terms(self.terms().map(|(d, c)| (d, c / rhs.clone())))
+    }
+}
+
+impl<T: Coefficient> Div<T> for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn div(mut self, rhs: T) -> Polynomial<T> {
+        self /= rhs;
+        self
+    }
+}
+
+impl<T: Coefficient> MulAssign<T> for Polynomial<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = scale(self, &rhs);
+    }
+}
+
+impl<T: Coefficient> DivAssign<T> for Polynomial<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = &*self / rhs;
+    }
+}
@@ -0,0 +1,196 @@
+use crate::error::NumericalError;
+use crate::polynomial::Polynomial;
+
+/// A family of classical orthogonal polynomials, each defined by a
+/// three-term recurrence
+///
+/// `P_(n+1)(x) = (a_n x + b_n) P_n(x) - c_n P_(n-1)(x)`, `P_0 = 1`, `P_(-1) = 0`.
+///
+/// Evaluation runs the recurrence directly, which is stable where
+/// expanding into monomials is not.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrthogonalFamily {
+    /// Legendre polynomials, orthogonal on `[-1, 1]`.
+    Legendre,
+    /// Chebyshev polynomials of the first kind, orthogonal on `[-1, 1]`
+    /// with weight `1 / sqrt(1 - x^2)`.
+    Chebyshev,
+    /// Physicists' Hermite polynomials, orthogonal on the real line with
+    /// weight `exp(-x^2)`.
+    Hermite,
+    /// Laguerre polynomials, orthogonal on `[0, ∞)` with weight `exp(-x)`.
+    Laguerre,
+    /// Jacobi polynomials, orthogonal on `[-1, 1]` with weight
+    /// `(1 - x)^alpha (1 + x)^beta`, for `alpha, beta > -1`.
+    Jacobi { alpha: f64, beta: f64 },
+}
+
+impl OrthogonalFamily {
+    /// Returns `(a_n, b_n, c_n)` of the recurrence that produces `P_(n+1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics for Jacobi parameters not greater than `-1`.
+    pub fn recurrence(&self, n: usize) -> (f64, f64, f64) {
+        let k = n as f64;
+        match *self {
+            OrthogonalFamily::Legendre => ((2.0 * k + 1.0) / (k + 1.0), 0.0, k / (k + 1.0)),
+            OrthogonalFamily::Chebyshev if n == 0 => (1.0, 0.0, 0.0),
+            OrthogonalFamily::Chebyshev => (2.0, 0.0, 1.0),
+            OrthogonalFamily::Hermite => (2.0, 0.0, 2.0 * k),
+            OrthogonalFamily::Laguerre => {
+                (-1.0 / (k + 1.0), (2.0 * k + 1.0) / (k + 1.0), k / (k + 1.0))
+            }
+            OrthogonalFamily::Jacobi { alpha, beta } => {
+                assert!(
+                    alpha > -1.0 && beta > -1.0,
+                    "Jacobi parameters must exceed -1"
+                );
+                if n == 0 {
+                    return ((alpha + beta + 2.0) / 2.0, (alpha - beta) / 2.0, 0.0);
+                }
+                let s = 2.0 * k + alpha + beta;
+                let denominator = 2.0 * (k + 1.0) * (k + alpha + beta + 1.0) * s;
+                (
+                    (s + 1.0) * (s + 2.0) * s / denominator,
+                    (s + 1.0) * (alpha * alpha - beta * beta) / denominator,
+                    2.0 * (k + alpha) * (k + beta) * (s + 2.0) / denominator,
+                )
+            }
+        }
+    }
+
+    /// Returns `P_0, ..., P_n` in monomial form.
+    pub fn polynomials(&self, n: usize) -> Vec<Polynomial> {
+        let mut polynomials = vec![Polynomial::constant(1.0)];
+        let mut previous = Polynomial::zero();
+
+        for k in 0..n {
+            let (a, b, c) = self.recurrence(k);
+            let current = &polynomials[k];
+            let next = &(current * &Polynomial::from_coefficients(vec![b, a])) - &(previous * c);
+            previous = current.clone();
+            polynomials.push(next);
+        }
+
+        polynomials
+    }
+
+    /// Returns `P_n` in monomial form.
+    pub fn polynomial(&self, n: usize) -> Polynomial {
+        self.polynomials(n).pop().expect("P_0 is always present")
+    }
+
+    /// Evaluates `P_n(x)` with the recurrence.
+    pub fn evaluate(&self, n: usize, x: f64) -> f64 {
+        self.evaluate_with_derivative(n, x).0
+    }
+
+    /// Evaluates `P_n(x)` and `P_n'(x)` together, using the recurrence and
+    /// its derivative `P_(n+1)' = (a_n x + b_n) P_n' + a_n P_n - c_n P_(n-1)'`.
+    pub fn evaluate_with_derivative(&self, n: usize, x: f64) -> (f64, f64) {
+        let (mut previous, mut current) = (0.0, 1.0);
+        let (mut previous_derivative, mut derivative) = (0.0, 0.0);
+
+        for k in 0..n {
+            let (a, b, c) = self.recurrence(k);
+            let next = (a * x + b) * current - c * previous;
+            let next_derivative = (a * x + b) * derivative + a * current - c * previous_derivative;
+            (previous, current) = (current, next);
+            (previous_derivative, derivative) = (derivative, next_derivative);
+        }
+
+        (current, derivative)
+    }
+
+    /// Evaluates the series `Σ coefficients[k] P_k(x)` with Clenshaw's
+    /// algorithm, without forming any `P_k`.
+    pub fn evaluate_series(&self, coefficients: &[f64], x: f64) -> f64 {
+        // b_k = w_k + (a_k x + b_k) b_(k+1) - c_(k+1) b_(k+2), and since the
+        // recurrence also holds at k = 0 with P_(-1) = 0 the sum is b_0.
+        let mut next = 0.0;
+        let mut after_next = 0.0;
+
+        for (k, &w) in coefficients.iter().enumerate().rev() {
+            let (a, b, _) = self.recurrence(k);
+            let (_, _, c) = self.recurrence(k + 1);
+            let value = w + (a * x + b) * next - c * after_next;
+            after_next = next;
+            next = value;
+        }
+
+        next
+    }
+
+    /// Converts coefficients in this basis, `Σ coefficients[k] P_k`, into a
+    /// polynomial in monomial form.
+    pub fn to_monomial(&self, coefficients: &[f64]) -> Polynomial {
+        let Some(n) = coefficients.len().checked_sub(1) else {
+            return Polynomial::zero();
+        };
+
+        self.polynomials(n)
+            .into_iter()
+            .zip(coefficients)
+            .fold(Polynomial::zero(), |sum, (p, &w)| sum + p * w)
+    }
+
+    /// Expresses `p` in this basis, returning `w` with `p = Σ w[k] P_k`.
+    ///
+    /// Fails with [`NumericalError::InvalidDomain`] if `p` has negative
+    /// powers of `x`.
+    pub fn from_monomial(&self, p: &Polynomial) -> Result<Vec<f64>, NumericalError> {
+        if p.degrees().first().is_some_and(|&d| d < 0) {
+            return Err(NumericalError::InvalidDomain(
+                "negative powers have no expansion in orthogonal polynomials".to_string(),
+            ));
+        }
+        let Some(n) = p.degree() else {
+            return Ok(Vec::new());
+        };
+
+        // Peel off the leading term with the basis polynomial of the same
+        // degree until nothing is left.
+        let basis = self.polynomials(n as usize);
+        let mut remainder = p.clone();
+        let mut coefficients = vec![0.0; n as usize + 1];
+        for k in (0..=n).rev() {
+            let w = remainder.coefficient(k) / basis[k as usize].leading_coefficient();
+            coefficients[k as usize] = w;
+            remainder -= basis[k as usize].clone() * w;
+        }
+
+        Ok(coefficients)
+    }
+}
+
+impl Polynomial {
+    /// The Legendre polynomial `P_n`.
+    pub fn legendre(n: usize) -> Polynomial {
+        OrthogonalFamily::Legendre.polynomial(n)
+    }
+
+    /// The Chebyshev polynomial of the first kind `T_n`.
+    pub fn chebyshev(n: usize) -> Polynomial {
+        OrthogonalFamily::Chebyshev.polynomial(n)
+    }
+
+    /// The physicists' Hermite polynomial `H_n`.
+    pub fn hermite(n: usize) -> Polynomial {
+        OrthogonalFamily::Hermite.polynomial(n)
+    }
+
+    /// The Laguerre polynomial `L_n`.
+    pub fn laguerre(n: usize) -> Polynomial {
+        OrthogonalFamily::Laguerre.polynomial(n)
+    }
+
+    /// The Jacobi polynomial `P_n^(alpha, beta)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `alpha > -1` and `beta > -1`.
+    pub fn jacobi(n: usize, alpha: f64, beta: f64) -> Polynomial {
+        OrthogonalFamily::Jacobi { alpha, beta }.polynomial(n)
+    }
+}
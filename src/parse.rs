@@ -0,0 +1,296 @@
+use std::str::FromStr;
+
+use crate::coefficient::Coefficient;
+use crate::error::{NumericalError, ParseError, ParseErrorKind};
+use crate::polynomial::Polynomial;
+
+/// A cursor over the bytes of the input.
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.input[self.position..].starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            offset: self.position,
+            kind,
+        }
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.input[self.position..].chars().next() {
+            Some(c) => self.error(ParseErrorKind::UnexpectedCharacter(c)),
+            None => self.error(ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn eat_digits(&mut self) -> usize {
+        let start = self.position;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    /// Scans a decimal literal such as `12`, `.5`, `3.` or `2.5e-3`, or a
+    /// fraction of two such as `1/2`, and returns its text.
+    fn number(&mut self) -> Option<&'a str> {
+        let start = self.position;
+        let mut digits = self.eat_digits();
+        if self.eat(".") {
+            digits += self.eat_digits();
+        }
+        if digits == 0 {
+            self.position = start;
+            return None;
+        }
+
+        // Only treat `e` as an exponent marker if digits follow it.
+        let mantissa_end = self.position;
+        if self.eat("e") || self.eat("E") {
+            let _ = self.eat("+") || self.eat("-");
+            if self.eat_digits() == 0 {
+                self.position = mantissa_end;
+            }
+        }
+
+        let numerator_end = self.position;
+        if self.eat("/") && self.eat_digits() == 0 {
+            self.position = numerator_end;
+        }
+
+        Some(&self.input[start..self.position])
+    }
+
+    /// Parses a coefficient written as a number, a number in parentheses
+    /// such as `(1/2)`, or a LaTeX fraction `\frac{1}{2}`.
+    fn coefficient<T>(&mut self) -> Result<Option<T>, ParseError>
+    where
+        T: Coefficient + FromStr,
+    {
+        let start = self.position;
+        let text = if self.eat("(") {
+            self.skip_whitespace();
+            let text = self.number().ok_or_else(|| self.unexpected())?;
+            self.skip_whitespace();
+            if !self.eat(")") {
+                return Err(self.unexpected());
+            }
+            text.to_string()
+        } else if self.eat("\\frac{") {
+            let numerator = self.number().ok_or_else(|| self.unexpected())?;
+            if !self.eat("}{") {
+                return Err(self.unexpected());
+            }
+            let denominator = self.number().ok_or_else(|| self.unexpected())?;
+            if !self.eat("}") {
+                return Err(self.unexpected());
+            }
+            format!("{}/{}", numerator, denominator)
+        } else {
+            match self.number() {
+                Some(text) => text.to_string(),
+                None => return Ok(None),
+            }
+        };
+
+        text.parse().map(Some).map_err(|_| ParseError {
+            offset: start,
+            kind: ParseErrorKind::InvalidCoefficient,
+        })
+    }
+
+    /// Parses a power written in superscript digits, such as `⁻¹²`.
+    fn superscript(&mut self) -> Result<Option<i32>, ParseError> {
+        let start = self.position;
+        let mut text = String::new();
+        for c in self.input[start..].chars() {
+            let digit = match c {
+                '⁻' if text.is_empty() => '-',
+                '⁰' => '0',
+                '¹' => '1',
+                '²' => '2',
+                '³' => '3',
+                '⁴' => '4',
+                '⁵' => '5',
+                '⁶' => '6',
+                '⁷' => '7',
+                '⁸' => '8',
+                '⁹' => '9',
+                _ => break,
+            };
+            text.push(digit);
+            self.position += c.len_utf8();
+        }
+
+        match text.as_str() {
+            "" => Ok(None),
+            "-" => Err(self.unexpected()),
+            _ => text.parse().map(Some).map_err(|_| ParseError {
+                offset: start,
+                kind: ParseErrorKind::InvalidExponent,
+            }),
+        }
+    }
+
+    /// Parses the exponent after `^` or `**`, which may carry a sign and,
+    /// as in LaTeX, be wrapped in one level of braces.
+    fn exponent(&mut self) -> Result<i32, ParseError> {
+        self.skip_whitespace();
+        if !self.eat("{") {
+            return self.signed_integer();
+        }
+
+        self.skip_whitespace();
+        let exponent = self.signed_integer()?;
+        self.skip_whitespace();
+        if !self.eat("}") {
+            return Err(self.unexpected());
+        }
+        Ok(exponent)
+    }
+
+    /// Parses an optionally signed integer exponent.
+    fn signed_integer(&mut self) -> Result<i32, ParseError> {
+        let start = self.position;
+        let _ = self.eat("+") || self.eat("-");
+        self.skip_whitespace();
+        let digits_start = self.position;
+        if self.eat_digits() == 0 {
+            return Err(self.unexpected());
+        }
+
+        let sign = if self.input[start..digits_start].starts_with('-') {
+            "-"
+        } else {
+            ""
+        };
+        format!("{}{}", sign, &self.input[digits_start..self.position])
+            .parse()
+            .map_err(|_| ParseError {
+                offset: start,
+                kind: ParseErrorKind::InvalidExponent,
+            })
+    }
+
+    /// Parses one term without its leading sign.
+    fn term<T>(&mut self) -> Result<(i32, T), ParseError>
+    where
+        T: Coefficient + FromStr,
+    {
+        let coefficient = self.coefficient::<T>()?;
+
+        self.skip_whitespace();
+        let checkpoint = self.position;
+        if coefficient.is_some() && self.eat("*") && !self.eat("*") {
+            self.skip_whitespace();
+            if self.peek() != Some(b'x') {
+                return Err(self.unexpected());
+            }
+        } else {
+            self.position = checkpoint;
+        }
+
+        if !self.eat("x") {
+            return match coefficient {
+                Some(c) => {
+                    self.position = checkpoint;
+                    Ok((0, c))
+                }
+                None => Err(self.unexpected()),
+            };
+        }
+
+        let after_variable = self.position;
+        if let Some(degree) = self.superscript()? {
+            return Ok((degree, coefficient.unwrap_or_else(T::one)));
+        }
+        self.skip_whitespace();
+        let degree = if self.eat("^") || self.eat("**") {
+            self.exponent()?
+        } else {
+            self.position = after_variable;
+            1
+        };
+
+        Ok((degree, coefficient.unwrap_or_else(T::one)))
+    }
+}
+
+impl<T> FromStr for Polynomial<T>
+where
+    T: Coefficient + FromStr,
+{
+    type Err = NumericalError;
+
+    /// Parses conventional notation such as `3x^2 - 2x + 1`, failing with
+    /// [`NumericalError::Parse`] on malformed input.
+    ///
+    /// Terms are separated by `+` or `-`, and the first term may carry a
+    /// sign. Coefficients are decimal literals with optional fraction and
+    /// scientific exponent (`2.5e-3x`), or fractions `n/d` for coefficient
+    /// types that parse them, such as [`Rational`](crate::Rational).
+    /// Either may be parenthesized, as in `(1/2)x`, or followed by `*`, and
+    /// they default to one. Powers are written `x^n` or `x**n` and may be
+    /// negative. Repeated degrees are summed, as in [`Polynomial::new`].
+    ///
+    /// The [`Notation::Unicode`](crate::Notation::Unicode) and
+    /// [`Notation::Latex`](crate::Notation::Latex) forms `x²` and
+    /// `\frac{1}{2}x^{2}` are accepted too, so every notation but MathML
+    /// reads back.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            input: s,
+            position: 0,
+        };
+        let mut terms = Vec::new();
+
+        parser.skip_whitespace();
+        if parser.peek().is_none() {
+            return Err(parser.error(ParseErrorKind::Empty).into());
+        }
+
+        loop {
+            let negative = if parser.eat("-") {
+                true
+            } else {
+                let signed = parser.eat("+");
+                if !signed && !terms.is_empty() {
+                    return Err(parser.unexpected().into());
+                }
+                false
+            };
+            parser.skip_whitespace();
+
+            let (degree, coefficient) = parser.term::<T>()?;
+            terms.push((degree, if negative { -coefficient } else { coefficient }));
+
+            parser.skip_whitespace();
+            if parser.peek().is_none() {
+                break;
+            }
+        }
+
+        Ok(Polynomial::from_terms(terms))
+    }
+}
@@ -0,0 +1,233 @@
+use crate::coefficient::Coefficient;
+use crate::error::NumericalError;
+
+/// A sparse polynomial stored as parallel vectors of coefficients and
+/// degrees.
+///
+/// Every constructor and operation leaves the terms in canonical form:
+/// sorted by strictly ascending degree with no zero coefficients, so the
+/// zero polynomial has no terms at all. Degrees are `i32`, so negative
+/// powers of `x` are allowed. Multiplying polynomials panics if a product
+/// degree overflows `i32` rather than wrapping around.
+///
+/// Coefficients are `f64` unless another [`Coefficient`] type is given.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<T = f64> {
+    pub(crate) coefficients: Vec<T>,
+    pub(crate) degrees: Vec<i32>,
+}
+
+impl<T: Coefficient> Polynomial<T> {
+    /// Builds a polynomial from matching coefficient and degree vectors.
+    ///
+    /// The terms may be given in any order; they are sorted by degree,
+    /// coefficients of repeated degrees are summed and zero terms dropped.
+    pub fn new(coefficients: Vec<T>, degrees: Vec<i32>) -> Result<Self, NumericalError> {
+        if coefficients.len() != degrees.len() {
+            return Err(NumericalError::LengthMismatch {
+                left: coefficients.len(),
+                right: degrees.len(),
+            });
+        }
+
+        Ok(Self::from_terms(degrees.into_iter().zip(coefficients)))
+    }
+
+    /// Builds a polynomial from `(degree, coefficient)` pairs.
+    pub fn from_terms<I>(terms: I) -> Self
+    where
+        I: IntoIterator<Item = (i32, T)>,
+    {
+        let mut combined: Vec<(i32, T)> = terms.into_iter().collect();
+
+        combined.sort_by_key(|a| a.0);
+
+        let mut merged: Vec<(i32, T)> = Vec::with_capacity(combined.len());
+        for (degree, coefficient) in combined {
+            match merged.last_mut() {
+                Some(last) if last.0 == degree => last.1 = last.1.clone() + coefficient,
+                _ => merged.push((degree, coefficient)),
+            }
+        }
+
+        Self::from_sorted_terms(merged)
+    }
+
+    /// Builds a polynomial from terms already sorted by strictly ascending
+    /// degree, dropping zero coefficients.
+    pub(crate) fn from_sorted_terms<I>(terms: I) -> Self
+    where
+        I: IntoIterator<Item = (i32, T)>,
+    {
+        let (degrees, coefficients): (Vec<i32>, Vec<T>) = terms
+            .into_iter()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .unzip();
+
+        Self {
+            coefficients,
+            degrees,
+        }
+    }
+
+    /// Builds a polynomial from dense coefficients, where `coefficients[i]`
+    /// multiplies `x^i`.
+    pub fn from_coefficients(coefficients: Vec<T>) -> Self {
+        Self::from_sorted_terms((0..).zip(coefficients))
+    }
+
+    /// The polynomial with no terms.
+    pub fn zero() -> Self {
+        Self {
+            coefficients: Vec::new(),
+            degrees: Vec::new(),
+        }
+    }
+
+    /// The constant polynomial `c`.
+    pub fn constant(c: T) -> Self {
+        Self::monomial(c, 0)
+    }
+
+    /// The single term `coefficient * x^degree`.
+    pub fn monomial(coefficient: T, degree: i32) -> Self {
+        Self::from_sorted_terms([(degree, coefficient)])
+    }
+
+    /// Coefficients in ascending order of degree.
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Degrees of the stored terms, in ascending order.
+    pub fn degrees(&self) -> &[i32] {
+        &self.degrees
+    }
+
+    /// Iterates over `(degree, coefficient)` pairs in ascending order of degree.
+    pub fn terms(&self) -> impl DoubleEndedIterator<Item = (i32, T)> + '_ {
+        self.degrees
+            .iter()
+            .cloned()
+            .zip(self.coefficients.iter().cloned())
+    }
+
+    /// Number of stored terms.
+    pub fn len(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// Returns `true` if the polynomial has no stored terms, i.e. it is the
+    /// zero polynomial.
+    pub fn is_empty(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Highest degree with a non-zero coefficient, or `None` for the zero
+    /// polynomial.
+    pub fn degree(&self) -> Option<i32> {
+        self.degrees.last().copied()
+    }
+
+    /// Coefficient of the highest-degree term, or zero for the zero
+    /// polynomial.
+    pub fn leading_coefficient(&self) -> T {
+        self.coefficients.last().cloned().unwrap_or_else(T::zero)
+    }
+
+    /// Coefficient of `x^degree`, which is zero if there is no such term.
+    pub fn coefficient(&self, degree: i32) -> T {
+        self.degrees
+            .binary_search(&degree)
+            .map_or_else(|_| T::zero(), |i| self.coefficients[i].clone())
+    }
+
+    /// Converts every coefficient with `f`, for example from `f64` to
+    /// [`Rational`](crate::Rational), dropping terms that become zero.
+    pub fn map_coefficients<U, F>(&self, mut f: F) -> Polynomial<U>
+    where
+        U: Coefficient,
+        F: FnMut(T) -> U,
+    {
+        Polynomial::from_sorted_terms(self.terms().map(|(d, c)| (d, f(c))))
+    }
+
+    /// Drops every term whose coefficient has magnitude at most `eps`.
+    pub fn trim(&mut self, eps: f64) {
+        let terms = std::mem::take(self)
+            .into_iter()
+            .filter(|(_, coefficient)| coefficient.magnitude() > eps);
+        *self = Self::from_sorted_terms(terms);
+    }
+
+    /// Returns the derivative with respect to `x`.
+    pub fn differentiate(&self) -> Polynomial<T> {
+        Self::from_sorted_terms(
+            self.terms()
+                .filter(|&(degree, _)| degree != 0)
+                .map(|(degree, coefficient)| (degree - 1, coefficient * T::from_i32(degree))),
+        )
+    }
+
+    /// Replaces the polynomial with its derivative, reusing its storage.
+    pub fn differentiate_mut(&mut self) {
+        if let Ok(i) = self.degrees.binary_search(&0) {
+            self.degrees.remove(i);
+            self.coefficients.remove(i);
+        }
+
+        for (degree, coefficient) in self.degrees.iter_mut().zip(&mut self.coefficients) {
+            *coefficient = coefficient.clone() * T::from_i32(*degree);
+            *degree -= 1;
+        }
+
+        // Only reachable through overflow or underflow, but the form must stay
+        // canonical.
+        if self.coefficients.iter().any(T::is_zero) {
+            *self = Self::from_sorted_terms(std::mem::take(self));
+        }
+    }
+
+    /// Returns the `n`-th derivative, computed in one pass: `c x^d` becomes
+    /// `c d (d - 1) ... (d - n + 1) x^(d - n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a resulting degree does not fit in an `i32`.
+    pub fn nth_derivative(&self, n: u32) -> Polynomial<T> {
+        let n = i32::try_from(n).expect("derivative order overflows i32");
+
+        Self::from_sorted_terms(
+            self.terms()
+                // Non-negative powers below `n` vanish; negative ones never do.
+                .filter(|&(degree, _)| degree < 0 || degree >= n)
+                .map(|(degree, coefficient)| {
+                    let lowered = degree.checked_sub(n).expect("degree overflows i32");
+                    let factor =
+                        (lowered + 1..=degree).fold(coefficient, |acc, k| acc * T::from_i32(k));
+                    (lowered, factor)
+                }),
+        )
+    }
+}
+
+impl<T: Coefficient> Default for Polynomial<T> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T> IntoIterator for Polynomial<T> {
+    type Item = (i32, T);
+    type IntoIter = std::iter::Zip<std::vec::IntoIter<i32>, std::vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.degrees.into_iter().zip(self.coefficients)
+    }
+}
+
+impl<T: Coefficient> FromIterator<(i32, T)> for Polynomial<T> {
+    fn from_iter<I: IntoIterator<Item = (i32, T)>>(iter: I) -> Self {
+        Self::from_terms(iter)
+    }
+}
@@ -0,0 +1,311 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use crate::bigint::{forward_owned, BigInt};
+use crate::coefficient::{Coefficient, Field};
+use crate::error::{NumericalError, ParseError, ParseErrorKind};
+
+/// An exact fraction of two [`BigInt`]s.
+///
+/// Always kept in lowest terms with a positive denominator, so equal values
+/// have equal representations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: BigInt,
+    denominator: BigInt,
+}
+
+impl Rational {
+    /// Builds `numerator / denominator` in lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub fn new(numerator: BigInt, denominator: BigInt) -> Self {
+        assert!(denominator.signum() != 0, "rational with zero denominator");
+
+        let divisor = numerator.gcd(&denominator);
+        let (mut numerator, mut denominator) = (&numerator / &divisor, &denominator / &divisor);
+        if denominator.is_negative() {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    pub fn from_integer(n: BigInt) -> Self {
+        Self {
+            numerator: n,
+            denominator: BigInt::from(1),
+        }
+    }
+
+    /// Converts a finite `f64` exactly, returning `None` for NaN and
+    /// infinities.
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        if value == 0.0 {
+            return Some(Self::zero());
+        }
+
+        let bits = value.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), exponent - 1075)
+        };
+
+        let mantissa = BigInt::from(mantissa);
+        let mantissa = if value < 0.0 { -mantissa } else { mantissa };
+        Some(if exponent >= 0 {
+            Self::from_integer(mantissa.shl(exponent as u64))
+        } else {
+            Self::new(mantissa, BigInt::from(1).shl(exponent.unsigned_abs()))
+        })
+    }
+
+    pub fn numerator(&self) -> &BigInt {
+        &self.numerator
+    }
+
+    /// The denominator, which is always positive.
+    pub fn denominator(&self) -> &BigInt {
+        &self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == BigInt::from(1)
+    }
+
+    pub fn abs(&self) -> Rational {
+        Self {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    /// The nearest `f64` to the fraction, with ties rounded to even, or an
+    /// infinity if it is out of range.
+    pub fn to_f64(&self) -> f64 {
+        if self.numerator.signum() == 0 {
+            return 0.0;
+        }
+
+        // Scale so the integer quotient has at least 65 significant bits,
+        // remembering whether the division was inexact.
+        let numerator = self.numerator.abs();
+        let shift = self.denominator.bits() as i64 - numerator.bits() as i64 + 65;
+        let (quotient, remainder) = if shift >= 0 {
+            numerator.shl(shift as u64).div_rem(&self.denominator)
+        } else {
+            numerator.div_rem(&self.denominator.shl(shift.unsigned_abs()))
+        };
+        let inexact = remainder.signum() != 0;
+
+        // The value is `quotient * 2^-shift`. Keep 53 bits, or fewer for
+        // subnormals, whose lowest bit is worth `2^-1074`, and round the
+        // discarded bits once.
+        let top = quotient.bits() as i64 - 1 - shift;
+        let lowest = (top - 52).max(-1074);
+        let discarded = (lowest + shift) as u64;
+        let (mantissa, rest) = quotient.div_rem(&BigInt::from(1).shl(discarded));
+        let half = BigInt::from(1).shl(discarded - 1);
+        let odd = mantissa.div_rem(&BigInt::from(2)).1.signum() != 0;
+        let mantissa = match rest.cmp(&half) {
+            Ordering::Greater => &mantissa + &BigInt::from(1),
+            Ordering::Equal if inexact || odd => &mantissa + &BigInt::from(1),
+            _ => mantissa,
+        };
+
+        // The mantissa has at most 54 bits and is a power of two if it has
+        // 54, so it converts exactly, and the split scaling is exact until
+        // the result overflows.
+        let exponent = lowest.clamp(i32::MIN as i64 / 2, i32::MAX as i64 / 2) as i32;
+        let value =
+            mantissa.to_f64() * 2f64.powi(exponent / 2) * 2f64.powi(exponent - exponent / 2);
+        if self.numerator.is_negative() {
+            -value
+        } else {
+            value
+        }
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(n: BigInt) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Self::from_integer(BigInt::from(n))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: &Rational) -> Rational {
+        Rational::new(
+            &(&self.numerator * &rhs.denominator) + &(&rhs.numerator * &self.denominator),
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl Sub for &Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: &Rational) -> Rational {
+        Rational::new(
+            &(&self.numerator * &rhs.denominator) - &(&rhs.numerator * &self.denominator),
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl Mul for &Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: &Rational) -> Rational {
+        Rational::new(
+            &self.numerator * &rhs.numerator,
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl Div for &Rational {
+    type Output = Rational;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: &Rational) -> Rational {
+        Rational::new(
+            &self.numerator * &rhs.denominator,
+            &self.denominator * &rhs.numerator,
+        )
+    }
+}
+
+forward_owned!(Rational, Add, add; Sub, sub; Mul, mul; Div, div);
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -&self.numerator,
+            denominator: self.denominator.clone(),
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Coefficient for Rational {
+    fn zero() -> Self {
+        Self::from_integer(BigInt::default())
+    }
+
+    fn one() -> Self {
+        Self::from_integer(BigInt::from(1))
+    }
+
+    fn from_i32(n: i32) -> Self {
+        Self::from_integer(BigInt::from(n))
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.to_f64().abs()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numerator.signum() == 0
+    }
+}
+
+impl Field for Rational {}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl FromStr for Rational {
+    type Err = NumericalError;
+
+    /// Parses `"n"` or `"n/d"` with decimal integers `n` and non-zero `d`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('/') {
+            Some((numerator, denominator)) => {
+                let offset = numerator.len() + 1;
+                let denominator = parse_integer(denominator, offset)?;
+                if denominator.signum() == 0 {
+                    return Err(ParseError {
+                        offset,
+                        kind: ParseErrorKind::InvalidNumber,
+                    }
+                    .into());
+                }
+                Ok(Self::new(parse_integer(numerator, 0)?, denominator))
+            }
+            None => Ok(Self::from_integer(parse_integer(s, 0)?)),
+        }
+    }
+}
+
+/// Parses a [`BigInt`] surrounded by optional whitespace, reporting errors
+/// relative to the start of the enclosing string at `offset`.
+fn parse_integer(s: &str, offset: usize) -> Result<BigInt, NumericalError> {
+    let leading = s.len() - s.trim_start().len();
+    s.trim().parse().map_err(|error| match error {
+        NumericalError::Parse(ParseError {
+            offset: inner,
+            kind,
+        }) => ParseError {
+            offset: offset + leading + inner,
+            kind,
+        }
+        .into(),
+        other => other,
+    })
+}
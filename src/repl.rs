@@ -0,0 +1,696 @@
+//! The interactive `numerical repl` session.
+//!
+//! Lines are either an expression, which is evaluated and printed, or an
+//! assignment `name = expression`. Expressions are built from numbers, the
+//! variable `x`, names bound earlier, `+ - * / % ^`, implicit multiplication
+//! (`2x`, `3(x + 1)`, `(x + 1)(x - 1)`), derivatives (`p'`), evaluation
+//! (`p(3)`) and composition (`p(q)`) of bound names, and the functions listed
+//! in [`HELP`].
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{BufRead, IsTerminal, Write};
+
+use numerical::{Complex64, NumericalError, ParseError, ParseErrorKind, Polynomial};
+
+/// Deepest nesting of parentheses, arguments and unary minus signs the
+/// evaluator accepts, which keeps hostile input from overflowing the stack.
+const MAX_DEPTH: usize = 128;
+
+const HELP: &str = "\
+  p = x^2 + 2x + 1     bind a name (x is the variable)
+  p * q, p / q, p % q  arithmetic; / and % are polynomial division
+  2x, (x+1)(x-1)       juxtaposition multiplies
+  p^3                  integer powers
+  p'  p''              derivatives
+  p(3)                 evaluate a bound name at a point
+  p(q)                 composition p(q(x)) of a bound name
+  diff(p, n)           n-th derivative
+  integrate(p)         antiderivative with zero constant term
+  integrate(p, a, b)   definite integral over [a, b]
+  roots(p)             complex roots
+  real_roots(p)        real roots
+  degree(p)            highest degree
+  :vars                list bound names
+  :history             list previous lines
+  :help                show this message
+  :quit                leave (or end of input)";
+
+/// A value an expression can produce.
+#[derive(Debug, Clone)]
+enum Value {
+    Number(f64),
+    Polynomial(Polynomial),
+    Roots(Vec<Complex64>),
+}
+
+impl Value {
+    fn describe(&self) -> &'static str {
+        match self {
+            Value::Number(_) => "a number",
+            Value::Polynomial(_) => "a polynomial",
+            Value::Roots(_) => "a list of roots",
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Polynomial(p) => write!(f, "{}", p),
+            Value::Roots(roots) => {
+                write!(f, "[")?;
+                for (i, root) in roots.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    if root.im == 0.0 {
+                        write!(f, "{}", root.re)?;
+                    } else {
+                        write!(f, "{}", root)?;
+                    }
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// Why a line could not be run.
+#[derive(Debug)]
+enum ReplError {
+    Syntax(ParseError),
+    Undefined(String),
+    /// An operation was applied to values of the wrong kind.
+    Type(String),
+    Numerical(NumericalError),
+}
+
+impl fmt::Display for ReplError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplError::Syntax(error) => write!(f, "{}", error),
+            ReplError::Undefined(name) => write!(f, "`{}` is not defined", name),
+            ReplError::Type(message) => write!(f, "{}", message),
+            ReplError::Numerical(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl From<NumericalError> for ReplError {
+    fn from(error: NumericalError) -> Self {
+        ReplError::Numerical(error)
+    }
+}
+
+fn syntax(offset: usize, kind: ParseErrorKind) -> ReplError {
+    ReplError::Syntax(ParseError { offset, kind })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Name(String),
+    Symbol(char),
+}
+
+/// Splits a line into tokens paired with their byte offsets.
+fn tokenize(line: &str) -> Result<Vec<(usize, Token)>, ReplError> {
+    let bytes = line.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        let start = i;
+
+        if c.is_ascii_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == b'.' {
+            while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
+                i += 1;
+            }
+            // Only take `e` as an exponent when digits follow, so `2e` is not
+            // swallowed from a name.
+            if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+                let mut j = i + 1;
+                if j < bytes.len() && (bytes[j] == b'+' || bytes[j] == b'-') {
+                    j += 1;
+                }
+                if j < bytes.len() && bytes[j].is_ascii_digit() {
+                    i = j;
+                    while i < bytes.len() && bytes[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+            let number = line[start..i]
+                .parse()
+                .map_err(|_| syntax(start, ParseErrorKind::InvalidNumber))?;
+            tokens.push((start, Token::Number(number)));
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            tokens.push((start, Token::Name(line[start..i].to_string())));
+        } else if b"+-*/%^'(),=".contains(&c) {
+            tokens.push((start, Token::Symbol(c as char)));
+            i += 1;
+        } else {
+            let c = line[start..].chars().next().unwrap_or_default();
+            return Err(syntax(start, ParseErrorKind::UnexpectedCharacter(c)));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive-descent evaluator over the tokens of one line.
+struct Parser<'a> {
+    tokens: &'a [(usize, Token)],
+    position: usize,
+    end: usize,
+    variables: &'a HashMap<String, Value>,
+    /// Number of nested `unary` calls currently open.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(_, token)| token)
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .map_or(self.end, |&(offset, _)| offset)
+    }
+
+    fn eat(&mut self, symbol: char) -> bool {
+        if self.peek() == Some(&Token::Symbol(symbol)) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, symbol: char) -> Result<(), ReplError> {
+        if self.eat(symbol) {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn unexpected(&self) -> ReplError {
+        let kind = match self.peek() {
+            None => ParseErrorKind::UnexpectedEnd,
+            Some(Token::Symbol(c)) => ParseErrorKind::UnexpectedCharacter(*c),
+            Some(Token::Name(name)) => {
+                ParseErrorKind::UnexpectedCharacter(name.chars().next().unwrap_or_default())
+            }
+            Some(Token::Number(_)) => ParseErrorKind::InvalidFormat("unexpected number"),
+        };
+        syntax(self.offset(), kind)
+    }
+
+    /// `expression := term (('+' | '-') term)*`
+    fn expression(&mut self) -> Result<Value, ReplError> {
+        let mut value = self.term()?;
+        loop {
+            if self.eat('+') {
+                value = add(value, self.term()?, false)?;
+            } else if self.eat('-') {
+                value = add(value, self.term()?, true)?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// `term := unary (('*' | '/' | '%')? unary)*`, where a missing operator
+    /// before a number, name or parenthesis means multiplication.
+    fn term(&mut self) -> Result<Value, ReplError> {
+        let mut value = self.unary()?;
+        loop {
+            if self.eat('*') {
+                value = multiply(value, self.unary()?)?;
+            } else if self.eat('/') {
+                value = divide(value, self.unary()?, false)?;
+            } else if self.eat('%') {
+                value = divide(value, self.unary()?, true)?;
+            } else if matches!(
+                self.peek(),
+                Some(Token::Number(_) | Token::Name(_) | Token::Symbol('('))
+            ) {
+                value = multiply(value, self.power()?)?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// `unary := '-' unary | power`
+    ///
+    /// Every level of nesting passes through here, so this is where the
+    /// depth is limited.
+    fn unary(&mut self) -> Result<Value, ReplError> {
+        if self.depth == MAX_DEPTH {
+            return Err(syntax(
+                self.offset(),
+                ParseErrorKind::InvalidFormat("nested too deeply"),
+            ));
+        }
+        self.depth += 1;
+        let value = if self.eat('-') {
+            self.unary().map(negate)
+        } else {
+            self.power()
+        };
+        self.depth -= 1;
+        value
+    }
+
+    /// `power := postfix ('^' unary)?`
+    fn power(&mut self) -> Result<Value, ReplError> {
+        let base = self.postfix()?;
+        if !self.eat('^') {
+            return Ok(base);
+        }
+        power(base, self.unary()?)
+    }
+
+    /// `postfix := primary ('\'' | '(' expression ')')*`, where only a
+    /// bound name can be applied to a parenthesized argument; after anything
+    /// else, including `x`, a parenthesis is left to `term` as a factor.
+    fn postfix(&mut self) -> Result<Value, ReplError> {
+        let applicable = matches!(
+            self.peek(),
+            Some(Token::Name(name)) if self.variables.contains_key(name)
+        );
+        let mut value = self.primary()?;
+        loop {
+            if self.eat('\'') {
+                value = Value::Polynomial(derivative(polynomial(value)?, 1)?);
+            } else if applicable
+                && self.peek() == Some(&Token::Symbol('('))
+                && matches!(value, Value::Polynomial(_))
+            {
+                self.position += 1;
+                let argument = self.expression()?;
+                self.expect(')')?;
+                let p = polynomial(value)?;
+                value = match argument {
+                    Value::Polynomial(inner) => {
+                        let ((pl, ph), (ql, qh)) = (degree_range(&p), degree_range(&inner));
+                        for degree in [pl * ql, pl * qh, ph * ql, ph * qh] {
+                            check_degree(degree)?;
+                        }
+                        Value::Polynomial(p.compose(&inner)?)
+                    }
+                    x => Value::Number(p.compute(number(x)?)),
+                };
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// `primary := number | name | function '(' arguments ')' | '(' expression ')'`
+    fn primary(&mut self) -> Result<Value, ReplError> {
+        let Some((_, token)) = self.tokens.get(self.position).cloned() else {
+            return Err(self.unexpected());
+        };
+
+        match token {
+            Token::Number(n) => {
+                self.position += 1;
+                Ok(Value::Number(n))
+            }
+            Token::Symbol('(') => {
+                self.position += 1;
+                let value = self.expression()?;
+                self.expect(')')?;
+                Ok(value)
+            }
+            Token::Name(name) => {
+                self.position += 1;
+                if name == "x" {
+                    return Ok(Value::Polynomial(Polynomial::monomial(1.0, 1)));
+                }
+                if let Some(value) = self.variables.get(&name) {
+                    return Ok(value.clone());
+                }
+                if self.peek() == Some(&Token::Symbol('(')) {
+                    self.position += 1;
+                    let arguments = self.arguments()?;
+                    return call(&name, arguments);
+                }
+                Err(ReplError::Undefined(name))
+            }
+            Token::Symbol(_) => Err(self.unexpected()),
+        }
+    }
+
+    /// Comma-separated arguments up to and including the closing parenthesis.
+    fn arguments(&mut self) -> Result<Vec<Value>, ReplError> {
+        let mut arguments = Vec::new();
+        if self.eat(')') {
+            return Ok(arguments);
+        }
+        loop {
+            arguments.push(self.expression()?);
+            if self.eat(')') {
+                return Ok(arguments);
+            }
+            self.expect(',')?;
+        }
+    }
+}
+
+fn polynomial(value: Value) -> Result<Polynomial, ReplError> {
+    match value {
+        Value::Number(n) => Ok(Polynomial::constant(n)),
+        Value::Polynomial(p) => Ok(p),
+        other => Err(ReplError::Type(format!(
+            "expected a polynomial but found {}",
+            other.describe()
+        ))),
+    }
+}
+
+fn number(value: Value) -> Result<f64, ReplError> {
+    match value {
+        Value::Number(n) => Ok(n),
+        // A constant polynomial, such as the value of `x - x + 2`, is still
+        // usable where a number is expected.
+        Value::Polynomial(p) if p.degrees().iter().all(|&d| d == 0) => Ok(p.coefficient(0)),
+        other => Err(ReplError::Type(format!(
+            "expected a number but found {}",
+            other.describe()
+        ))),
+    }
+}
+
+fn add(left: Value, right: Value, subtract: bool) -> Result<Value, ReplError> {
+    match (left, right) {
+        (Value::Number(a), Value::Number(b)) => {
+            Ok(Value::Number(if subtract { a - b } else { a + b }))
+        }
+        (left, right) => {
+            let (a, b) = (polynomial(left)?, polynomial(right)?);
+            Ok(Value::Polynomial(if subtract { a - b } else { a + b }))
+        }
+    }
+}
+
+fn negate(value: Value) -> Value {
+    match value {
+        Value::Number(n) => Value::Number(-n),
+        Value::Polynomial(p) => Value::Polynomial(-p),
+        Value::Roots(roots) => Value::Roots(roots.into_iter().map(|z| -z).collect()),
+    }
+}
+
+/// The lowest and highest degree of `p`, or zero for the zero polynomial.
+fn degree_range(p: &Polynomial) -> (i64, i64) {
+    let mut terms = p.terms();
+    let low = terms.next().map_or(0, |(d, _)| d);
+    let high = terms.next_back().map_or(low, |(d, _)| d);
+    (i64::from(low), i64::from(high))
+}
+
+/// Fails unless `degree` fits in an `i32`, so that a result whose degree
+/// would overflow is reported instead of panicking.
+fn check_degree(degree: i64) -> Result<(), ReplError> {
+    if i32::try_from(degree).is_err() {
+        return Err(ReplError::Numerical(NumericalError::InvalidDomain(
+            format!("the degree {} does not fit in an i32", degree),
+        )));
+    }
+    Ok(())
+}
+
+/// The `order`-th derivative of `p`, if its lowest degree stays in range.
+fn derivative(p: Polynomial, order: u32) -> Result<Polynomial, ReplError> {
+    check_degree(degree_range(&p).0 - i64::from(order))?;
+    Ok(p.nth_derivative(order))
+}
+
+fn multiply(left: Value, right: Value) -> Result<Value, ReplError> {
+    match (left, right) {
+        (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a * b)),
+        (left, right) => {
+            let (left, right) = (polynomial(left)?, polynomial(right)?);
+            let ((ll, lh), (rl, rh)) = (degree_range(&left), degree_range(&right));
+            check_degree(ll + rl)?;
+            check_degree(lh + rh)?;
+            Ok(Value::Polynomial(left * right))
+        }
+    }
+}
+
+fn divide(left: Value, right: Value, remainder: bool) -> Result<Value, ReplError> {
+    match (left, right) {
+        (Value::Number(a), Value::Number(b)) if !remainder => Ok(Value::Number(a / b)),
+        (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a % b)),
+        (left, right) => {
+            let (left, right) = (polynomial(left)?, polynomial(right)?);
+            // The quotient's degree is the difference of the leading degrees.
+            let shift = degree_range(&left).1 - degree_range(&right).1;
+            if shift > 0 {
+                check_degree(shift)?;
+            }
+            let (quotient, rest) = left.div_rem(&right)?;
+            Ok(Value::Polynomial(if remainder { rest } else { quotient }))
+        }
+    }
+}
+
+fn power(base: Value, exponent: Value) -> Result<Value, ReplError> {
+    let exponent = number(exponent)?;
+    let base = match base {
+        Value::Number(b) => return Ok(Value::Number(b.powf(exponent))),
+        base => polynomial(base)?,
+    };
+
+    if exponent.fract() != 0.0 || exponent.abs() > i32::MAX as f64 {
+        return Err(ReplError::Type(
+            "polynomials can only be raised to integer powers".to_string(),
+        ));
+    }
+    let n = exponent as i32;
+    let (low, high) = degree_range(&base);
+    check_degree(low * i64::from(n))?;
+    check_degree(high * i64::from(n))?;
+
+    if n < 0 {
+        // Only a single term has a polynomial reciprocal.
+        return match base.terms().collect::<Vec<_>>()[..] {
+            [(degree, coefficient)] => Ok(Value::Polynomial(Polynomial::monomial(
+                coefficient.powi(n),
+                degree * n,
+            ))),
+            _ => Err(ReplError::Type(
+                "only single terms can be raised to negative powers".to_string(),
+            )),
+        };
+    }
+
+    Ok(Value::Polynomial(base.pow(n as u32)))
+}
+
+fn call(name: &str, arguments: Vec<Value>) -> Result<Value, ReplError> {
+    let result = match (name, &arguments[..]) {
+        ("roots", [p]) => Value::Roots(polynomial(p.clone())?.roots()),
+        ("real_roots", [p]) => Value::Roots(
+            polynomial(p.clone())?
+                .real_roots()
+                .into_iter()
+                .map(Complex64::from)
+                .collect(),
+        ),
+        ("degree", [p]) => Value::Number(
+            polynomial(p.clone())?
+                .degree()
+                .map_or(f64::NEG_INFINITY, f64::from),
+        ),
+        ("diff", [p]) => Value::Polynomial(derivative(polynomial(p.clone())?, 1)?),
+        ("diff", [p, order]) => {
+            let order = number(order.clone())?;
+            if order < 0.0 || order.fract() != 0.0 || order > i32::MAX as f64 {
+                return Err(ReplError::Type(
+                    "the order of a derivative must be a non-negative integer".to_string(),
+                ));
+            }
+            Value::Polynomial(derivative(polynomial(p.clone())?, order as u32)?)
+        }
+        ("integrate", [p]) => {
+            let p = polynomial(p.clone())?;
+            check_degree(degree_range(&p).1 + 1)?;
+            Value::Polynomial(p.integrate(0.0)?)
+        }
+        ("integrate", [p, a, b]) => {
+            let (a, b) = (number(a.clone())?, number(b.clone())?);
+            let p = polynomial(p.clone())?;
+            check_degree(degree_range(&p).1 + 1)?;
+            Value::Number(p.definite_integral(a, b)?)
+        }
+        ("roots" | "real_roots" | "degree" | "diff" | "integrate", _) => {
+            return Err(ReplError::Type(format!(
+                "{}() does not take {} argument{}",
+                name,
+                arguments.len(),
+                if arguments.len() == 1 { "" } else { "s" }
+            )))
+        }
+        _ => return Err(ReplError::Undefined(name.to_string())),
+    };
+
+    Ok(result)
+}
+
+/// Names that cannot be rebound.
+const RESERVED: &[&str] = &["x", "roots", "real_roots", "degree", "diff", "integrate"];
+
+/// The state of a session: bound names and every line entered so far.
+#[derive(Debug, Default)]
+struct Session {
+    variables: HashMap<String, Value>,
+    history: Vec<String>,
+}
+
+impl Session {
+    /// Runs one line, returning the text to print, if any.
+    fn execute(&mut self, line: &str) -> Result<Option<String>, ReplError> {
+        let tokens = tokenize(line)?;
+
+        // `name = ...` binds; anything else is evaluated and shown.
+        let (target, body) = match &tokens[..] {
+            [(_, Token::Name(name)), (_, Token::Symbol('=')), ..] => {
+                if RESERVED.contains(&name.as_str()) {
+                    return Err(ReplError::Type(format!("`{}` cannot be reassigned", name)));
+                }
+                (Some(name.clone()), &tokens[2..])
+            }
+            _ => (None, &tokens[..]),
+        };
+
+        let mut parser = Parser {
+            tokens: body,
+            position: 0,
+            end: line.len(),
+            variables: &self.variables,
+            depth: 0,
+        };
+        let value = parser.expression()?;
+        if parser.position < body.len() {
+            return Err(parser.unexpected());
+        }
+
+        match target {
+            Some(name) => {
+                let shown = format!("{} = {}", name, value);
+                self.variables.insert(name, value);
+                Ok(Some(shown))
+            }
+            None => Ok(Some(value.to_string())),
+        }
+    }
+
+    /// Handles a `:command`, returning `None` to end the session.
+    fn command(&self, command: &str) -> Option<String> {
+        Some(match command {
+            "quit" | "q" | "exit" => return None,
+            "help" | "h" => HELP.to_string(),
+            "vars" => {
+                let mut names: Vec<&String> = self.variables.keys().collect();
+                names.sort();
+                names
+                    .into_iter()
+                    .map(|name| format!("  {} = {}", name, self.variables[name]))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            "history" => self
+                .history
+                .iter()
+                .enumerate()
+                .map(|(i, line)| format!("{:>4}  {}", i + 1, line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            other => format!("unknown command :{} (try :help)", other),
+        })
+    }
+}
+
+/// Formats `error` for `line`, pointing at the offending position for
+/// syntax errors.
+fn report(line: &str, error: &ReplError, prompt_width: usize) -> String {
+    match error {
+        ReplError::Syntax(ParseError { offset, .. }) => {
+            let column = prompt_width + line[..*offset].chars().count();
+            format!("{}^\nerror: {}", " ".repeat(column), error)
+        }
+        _ => format!("error: {}", error),
+    }
+}
+
+/// Reads lines from stdin until `:quit` or the end of input. Prompts are
+/// only shown when stdin is a terminal, so scripts can be piped in.
+pub fn run() -> std::io::Result<()> {
+    const PROMPT: &str = "> ";
+
+    let interactive = std::io::stdin().is_terminal();
+    let mut session = Session::default();
+    let mut stdout = std::io::stdout();
+
+    if interactive {
+        writeln!(stdout, "numerical repl; type :help for help")?;
+    }
+
+    let mut lines = std::io::stdin().lock().lines();
+    loop {
+        if interactive {
+            write!(stdout, "{}", PROMPT)?;
+            stdout.flush()?;
+        }
+        let Some(line) = lines.next().transpose()? else {
+            break;
+        };
+        let line = line.trim_end();
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(command) = line.trim().strip_prefix(':') {
+            match session.command(command.trim()) {
+                Some(output) if !output.is_empty() => writeln!(stdout, "{}", output)?,
+                Some(_) => {}
+                None => break,
+            }
+            continue;
+        }
+
+        session.history.push(line.to_string());
+        match session.execute(line) {
+            Ok(Some(output)) => writeln!(stdout, "{}", output)?,
+            Ok(None) => {}
+            Err(error) => {
+                // Without a prompt in front of the line, echo it so the caret
+                // has something to point at.
+                let width = if interactive {
+                    PROMPT.len()
+                } else {
+                    writeln!(stdout, "{}", line)?;
+                    0
+                };
+                writeln!(stdout, "{}", report(line, &error, width))?;
+            }
+        }
+    }
+
+    Ok(())
+}
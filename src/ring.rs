@@ -0,0 +1,30 @@
+use std::ops::{Add, Mul};
+
+/// Values a [`Polynomial`](crate::Polynomial) can be evaluated at.
+///
+/// Multiplication need not be commutative, but every value must commute
+/// with its own powers, as square matrices do.
+pub trait Ring: Clone + Add<Output = Self> + Mul<Output = Self> {
+    /// The multiplicative identity with the same shape as `self`.
+    fn one_like(&self) -> Self;
+
+    /// Multiplies by a real scalar.
+    fn scale(&self, factor: f64) -> Self;
+
+    /// The multiplicative inverse, used for terms of negative degree.
+    fn recip(&self) -> Self;
+}
+
+impl Ring for f64 {
+    fn one_like(&self) -> Self {
+        1.0
+    }
+
+    fn scale(&self, factor: f64) -> Self {
+        self * factor
+    }
+
+    fn recip(&self) -> Self {
+        f64::recip(*self)
+    }
+}
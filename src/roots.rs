@@ -0,0 +1,269 @@
+use std::f64::consts::PI;
+
+use crate::complex::Complex64;
+use crate::polynomial::Polynomial;
+
+const MAX_ABERTH_ITERATIONS: usize = 500;
+const MAX_POLISH_ITERATIONS: usize = 50;
+
+/// Distance, relative to their modulus, under which Aberth approximations
+/// are treated as one multiple root. Approximations of a root of
+/// multiplicity `m` only agree to about `eps^(1/m)`, so this is deliberately
+/// loose; clusters are verified before being merged.
+const CLUSTER_TOLERANCE: f64 = 1e-3;
+
+/// Size, relative to `Σ |a_i| |x|^i`, below which a derivative is treated as
+/// vanishing when verifying a multiple root.
+const MULTIPLICITY_TOLERANCE: f64 = 1e-12;
+
+/// Relative size of the imaginary part below which a root is reported as real.
+const REAL_TOLERANCE: f64 = 1e-8;
+
+/// A root of a polynomial together with its multiplicity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Root {
+    pub value: Complex64,
+    pub multiplicity: usize,
+}
+
+impl Root {
+    /// Returns `true` if the root was classified as real, in which case its
+    /// imaginary part is exactly zero.
+    pub fn is_real(&self) -> bool {
+        self.value.im == 0.0
+    }
+}
+
+impl Polynomial {
+    /// Returns every complex root, repeated according to its multiplicity.
+    ///
+    /// Negative powers of `x` are factored out first, so a polynomial spanning
+    /// degrees `lo..=hi` with `lo < 0` has `hi - lo` roots, none of them zero.
+    /// The zero polynomial has no roots reported.
+    pub fn roots(&self) -> Vec<Complex64> {
+        self.roots_with_multiplicity()
+            .into_iter()
+            .flat_map(|root| std::iter::repeat_n(root.value, root.multiplicity))
+            .collect()
+    }
+
+    /// Returns the real roots in ascending order, repeated according to their
+    /// multiplicity.
+    pub fn real_roots(&self) -> Vec<f64> {
+        let mut roots: Vec<f64> = self
+            .roots_with_multiplicity()
+            .into_iter()
+            .filter(Root::is_real)
+            .flat_map(|root| std::iter::repeat_n(root.value.re, root.multiplicity))
+            .collect();
+
+        roots.sort_by(f64::total_cmp);
+        roots
+    }
+
+    /// Returns the distinct complex roots with their multiplicities.
+    ///
+    /// Approximations come from the Aberth–Ehrlich iteration. Nearby
+    /// approximations are grouped into multiple roots, which are then polished
+    /// with Newton's method on the derivative in which they become simple.
+    pub fn roots_with_multiplicity(&self) -> Vec<Root> {
+        let (Some(&low), Some(high)) = (self.degrees.first(), self.degree()) else {
+            return Vec::new();
+        };
+
+        let mut roots = Vec::new();
+        if low > 0 {
+            roots.push(Root {
+                value: Complex64::default(),
+                multiplicity: low as usize,
+            });
+        }
+
+        // Dividing by x^low leaves an ordinary polynomial with a non-zero
+        // constant term, whose roots are the non-zero roots of `self`.
+        let mut dense = vec![0.0; (high - low) as usize + 1];
+        for (degree, coefficient) in self.terms() {
+            dense[(degree - low) as usize] = coefficient;
+        }
+        if dense.len() == 1 {
+            return roots;
+        }
+
+        let reduced = Polynomial::from_coefficients(dense.clone());
+        for cluster in cluster(aberth(&dense)) {
+            roots.extend(resolve_cluster(&reduced, cluster));
+        }
+
+        roots
+    }
+}
+
+/// Evaluates dense `coefficients` and their derivative at `z` with Horner's
+/// scheme.
+fn horner_with_derivative(coefficients: &[f64], z: Complex64) -> (Complex64, Complex64) {
+    let mut value = Complex64::default();
+    let mut derivative = Complex64::default();
+
+    for &c in coefficients.iter().rev() {
+        derivative = derivative * z + value;
+        value = value * z + Complex64::from(c);
+    }
+
+    (value, derivative)
+}
+
+/// Simultaneously approximates all roots of the dense polynomial with
+/// Aberth–Ehrlich iteration.
+fn aberth(coefficients: &[f64]) -> Vec<Complex64> {
+    let n = coefficients.len() - 1;
+    let radius = (coefficients[0] / coefficients[n])
+        .abs()
+        .powf(1.0 / n as f64);
+    let radius = if radius.is_finite() && radius > 0.0 {
+        radius
+    } else {
+        1.0
+    };
+
+    let mut z: Vec<Complex64> = (0..n)
+        .map(|k| Complex64::from_polar(radius, 2.0 * PI * k as f64 / n as f64 + 0.4))
+        .collect();
+
+    for _ in 0..MAX_ABERTH_ITERATIONS {
+        let mut converged = true;
+
+        for k in 0..n {
+            let (value, derivative) = horner_with_derivative(coefficients, z[k]);
+            if value.norm() == 0.0 {
+                continue;
+            }
+
+            let repulsion = (0..n)
+                .filter(|&j| j != k)
+                .fold(Complex64::default(), |acc, j| {
+                    acc + Complex64::from(1.0) / (z[k] - z[j])
+                });
+            let ratio = value / derivative;
+            let correction = ratio / (Complex64::from(1.0) - ratio * repulsion);
+
+            if !correction.re.is_finite() || !correction.im.is_finite() {
+                continue;
+            }
+
+            z[k] = z[k] - correction;
+            if correction.norm() > 4.0 * f64::EPSILON * z[k].norm().max(f64::MIN_POSITIVE) {
+                converged = false;
+            }
+        }
+
+        if converged {
+            break;
+        }
+    }
+
+    z
+}
+
+fn cluster(approximations: Vec<Complex64>) -> Vec<Vec<Complex64>> {
+    let mut clusters: Vec<Vec<Complex64>> = Vec::new();
+
+    for z in approximations {
+        let tolerance = CLUSTER_TOLERANCE * z.norm();
+        match clusters
+            .iter_mut()
+            .find(|c| (mean(c) - z).norm() <= tolerance)
+        {
+            Some(c) => c.push(z),
+            None => clusters.push(vec![z]),
+        }
+    }
+
+    clusters
+}
+
+fn mean(points: &[Complex64]) -> Complex64 {
+    points.iter().fold(Complex64::default(), |acc, &z| acc + z) / points.len() as f64
+}
+
+/// Runs Newton's method on `f` from `start`, keeping the iterate with the
+/// smallest residual.
+fn newton(f: &Polynomial, start: Complex64) -> Complex64 {
+    let derivative = f.differentiate();
+    let mut best = start;
+    let mut best_residual = f.evaluate(&start).norm();
+    let mut z = start;
+
+    for _ in 0..MAX_POLISH_ITERATIONS {
+        if best_residual == 0.0 {
+            break;
+        }
+        let step = f.evaluate(&z) / derivative.evaluate(&z);
+        if !step.re.is_finite() || !step.im.is_finite() {
+            break;
+        }
+        z = z - step;
+
+        let residual = f.evaluate(&z).norm();
+        if residual < best_residual {
+            best = z;
+            best_residual = residual;
+        }
+        if step.norm() <= f64::EPSILON * z.norm() {
+            break;
+        }
+    }
+
+    best
+}
+
+/// Turns a cluster of approximations into polished roots. A cluster of `m`
+/// points is accepted as one root of multiplicity `m` if Newton's method on
+/// the `(m - 1)`-th derivative, where such a root is simple, stays inside the
+/// cluster and `p, p', ..., p^(m-1)` all vanish there; otherwise every point
+/// is polished as a simple root.
+fn resolve_cluster(p: &Polynomial, cluster: Vec<Complex64>) -> Vec<Root> {
+    let multiplicity = cluster.len();
+
+    if multiplicity > 1 {
+        let centre = mean(&cluster);
+        let derivative = p.nth_derivative(multiplicity as u32 - 1);
+        let polished = newton(&derivative, centre);
+
+        if (polished - centre).norm() <= CLUSTER_TOLERANCE * centre.norm()
+            && (0..multiplicity as u32).all(|k| vanishes(&p.nth_derivative(k), polished))
+        {
+            return vec![classify(p, polished, multiplicity)];
+        }
+    }
+
+    cluster
+        .into_iter()
+        .map(|z| classify(p, newton(p, z), 1))
+        .collect()
+}
+
+/// Returns `true` if `q(z)` is negligible next to `Σ |a_i| |z|^i`, the size
+/// rounding errors in evaluating `q` can reach.
+fn vanishes(q: &Polynomial, z: Complex64) -> bool {
+    let scale: f64 = q.terms().map(|(d, c)| c.abs() * z.norm().powi(d)).sum();
+    q.evaluate(&z).norm() <= MULTIPLICITY_TOLERANCE * scale
+}
+
+/// Snaps roots with a negligible imaginary part onto the real axis and
+/// polishes them there.
+fn classify(p: &Polynomial, value: Complex64, multiplicity: usize) -> Root {
+    if value.im.abs() > REAL_TOLERANCE * value.norm().max(1.0) {
+        return Root {
+            value,
+            multiplicity,
+        };
+    }
+
+    let derivative = p.nth_derivative(multiplicity as u32 - 1);
+    let polished = newton(&derivative, Complex64::from(value.re));
+
+    Root {
+        value: Complex64::from(polished.re),
+        multiplicity,
+    }
+}
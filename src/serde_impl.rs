@@ -0,0 +1,80 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{self, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::coefficient::Coefficient;
+use crate::polynomial::Polynomial;
+
+/// Serializes the terms as a sequence of `(degree, coefficient)` pairs.
+struct Terms<'a, T>(&'a Polynomial<T>);
+
+impl<T: Serialize> Serialize for Terms<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.degrees.len()))?;
+        for (degree, coefficient) in self.0.degrees.iter().zip(&self.0.coefficients) {
+            seq.serialize_element(&(degree, coefficient))?;
+        }
+        seq.end()
+    }
+}
+
+/// Uses the same `{"terms": [[degree, coefficient], ...]}` shape as
+/// [`Polynomial::to_json`].
+impl<T: Serialize> Serialize for Polynomial<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Polynomial", 1)?;
+        state.serialize_field("terms", &Terms(self))?;
+        state.end()
+    }
+}
+
+struct PolynomialVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for PolynomialVisitor<T>
+where
+    T: Coefficient + Deserialize<'de>,
+{
+    type Value = Polynomial<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a polynomial with a list of (degree, coefficient) terms")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let terms: Vec<(i32, T)> = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        Ok(Polynomial::from_terms(terms))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut terms: Option<Vec<(i32, T)>> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "terms" {
+                if terms.is_some() {
+                    return Err(de::Error::duplicate_field("terms"));
+                }
+                terms = Some(map.next_value()?);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+
+        let terms = terms.ok_or_else(|| de::Error::missing_field("terms"))?;
+        Ok(Polynomial::from_terms(terms))
+    }
+}
+
+/// Deserialized terms go through [`Polynomial::from_terms`], so the result
+/// is canonical.
+impl<'de, T> Deserialize<'de> for Polynomial<T>
+where
+    T: Coefficient + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("Polynomial", &["terms"], PolynomialVisitor(PhantomData))
+    }
+}
@@ -0,0 +1,362 @@
+//! Scalar root finders for closures and polynomials.
+//!
+//! Every solver stops after [`SolverOptions::max_iterations`] steps and
+//! reports how it finished through a [`Solution`] instead of panicking.
+
+use crate::error::NumericalError;
+use crate::polynomial::Polynomial;
+
+/// Stopping criteria shared by all solvers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverOptions {
+    /// Absolute tolerance on the step size or bracket width.
+    pub tolerance: f64,
+    /// Stop as soon as `|f(x)|` is at most this value.
+    pub residual_tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for SolverOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-12,
+            residual_tolerance: 0.0,
+            max_iterations: 100,
+        }
+    }
+}
+
+/// How a solver finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Converged,
+    /// The iteration limit was reached before the tolerance was met.
+    MaxIterations,
+    /// A Newton or secant step would divide by zero.
+    ZeroDerivative,
+    /// The function has the same sign at both ends of the bracket.
+    InvalidBracket,
+    /// The function returned a NaN or infinite value.
+    NonFinite,
+}
+
+/// The outcome of a solver run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Solution {
+    /// The best estimate of the root.
+    pub root: f64,
+    pub iterations: usize,
+    /// `|f(root)|`.
+    pub residual: f64,
+    pub status: Status,
+}
+
+impl Solution {
+    pub fn converged(&self) -> bool {
+        self.status == Status::Converged
+    }
+
+    /// Returns the root if the solver converged.
+    ///
+    /// An invalid bracket becomes [`NumericalError::InvalidDomain`]; every
+    /// other failure becomes [`NumericalError::NonConvergence`] with the
+    /// iteration count and last residual.
+    pub fn into_result(self) -> Result<f64, NumericalError> {
+        match self.status {
+            Status::Converged => Ok(self.root),
+            Status::InvalidBracket => Err(NumericalError::InvalidDomain(
+                "the function has the same sign at both ends of the bracket".to_string(),
+            )),
+            _ => Err(NumericalError::NonConvergence {
+                iterations: self.iterations,
+                residual: self.residual,
+            }),
+        }
+    }
+}
+
+fn finish(root: f64, value: f64, iterations: usize, status: Status) -> Solution {
+    let status = if status == Status::Converged && !value.is_finite() {
+        Status::NonFinite
+    } else {
+        status
+    };
+
+    Solution {
+        root,
+        iterations,
+        residual: value.abs(),
+        status,
+    }
+}
+
+/// Newton–Raphson iteration from `x0` using the derivative `df`.
+pub fn newton<F, D>(f: F, df: D, x0: f64, options: &SolverOptions) -> Solution
+where
+    F: Fn(f64) -> f64,
+    D: Fn(f64) -> f64,
+{
+    let mut x = x0;
+    let mut fx = f(x);
+
+    for i in 0..options.max_iterations {
+        if !fx.is_finite() {
+            return finish(x, fx, i, Status::NonFinite);
+        }
+        if fx.abs() <= options.residual_tolerance {
+            return finish(x, fx, i, Status::Converged);
+        }
+
+        let slope = df(x);
+        if slope == 0.0 {
+            return finish(x, fx, i, Status::ZeroDerivative);
+        }
+
+        let step = fx / slope;
+        x -= step;
+        fx = f(x);
+
+        if step.abs() <= options.tolerance {
+            return finish(x, fx, i + 1, Status::Converged);
+        }
+    }
+
+    finish(x, fx, options.max_iterations, Status::MaxIterations)
+}
+
+/// Secant iteration from the starting points `x0` and `x1`.
+pub fn secant<F>(f: F, x0: f64, x1: f64, options: &SolverOptions) -> Solution
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut x_prev, mut x) = (x0, x1);
+    let (mut f_prev, mut fx) = (f(x0), f(x1));
+
+    for i in 0..options.max_iterations {
+        if !fx.is_finite() {
+            return finish(x, fx, i, Status::NonFinite);
+        }
+        if fx.abs() <= options.residual_tolerance {
+            return finish(x, fx, i, Status::Converged);
+        }
+        if fx == f_prev {
+            return finish(x, fx, i, Status::ZeroDerivative);
+        }
+
+        let step = fx * (x - x_prev) / (fx - f_prev);
+        (x_prev, f_prev) = (x, fx);
+        x -= step;
+        fx = f(x);
+
+        if step.abs() <= options.tolerance {
+            return finish(x, fx, i + 1, Status::Converged);
+        }
+    }
+
+    finish(x, fx, options.max_iterations, Status::MaxIterations)
+}
+
+/// Checks that `[a, b]` brackets a root, returning a finished [`Solution`]
+/// if the bracket is invalid or an endpoint is already a root.
+fn check_bracket(a: f64, fa: f64, b: f64, fb: f64, options: &SolverOptions) -> Option<Solution> {
+    if !fa.is_finite() {
+        return Some(finish(a, fa, 0, Status::NonFinite));
+    }
+    if !fb.is_finite() {
+        return Some(finish(b, fb, 0, Status::NonFinite));
+    }
+    if fa.abs() <= options.residual_tolerance {
+        return Some(finish(a, fa, 0, Status::Converged));
+    }
+    if fb.abs() <= options.residual_tolerance {
+        return Some(finish(b, fb, 0, Status::Converged));
+    }
+    if fa.signum() == fb.signum() {
+        let (x, fx) = if fa.abs() < fb.abs() {
+            (a, fa)
+        } else {
+            (b, fb)
+        };
+        return Some(finish(x, fx, 0, Status::InvalidBracket));
+    }
+    None
+}
+
+/// Bisection on a bracket `[a, b]` where `f(a)` and `f(b)` differ in sign.
+pub fn bisection<F>(f: F, a: f64, b: f64, options: &SolverOptions) -> Solution
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut a, mut b) = (a, b);
+    let (mut fa, fb) = (f(a), f(b));
+    if let Some(solution) = check_bracket(a, fa, b, fb, options) {
+        return solution;
+    }
+
+    let mut mid = 0.5 * (a + b);
+    let mut fmid = f(mid);
+
+    for i in 0..options.max_iterations {
+        if !fmid.is_finite() {
+            return finish(mid, fmid, i, Status::NonFinite);
+        }
+        if fmid.abs() <= options.residual_tolerance || 0.5 * (b - a).abs() <= options.tolerance {
+            return finish(mid, fmid, i, Status::Converged);
+        }
+
+        if fmid.signum() == fa.signum() {
+            (a, fa) = (mid, fmid);
+        } else {
+            b = mid;
+        }
+        mid = 0.5 * (a + b);
+        fmid = f(mid);
+    }
+
+    finish(mid, fmid, options.max_iterations, Status::MaxIterations)
+}
+
+/// Shared implementation of regula falsi and its Illinois modification,
+/// which halves the weight of an endpoint retained twice in a row.
+fn false_position<F>(f: F, a: f64, b: f64, options: &SolverOptions, illinois: bool) -> Solution
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if let Some(solution) = check_bracket(a, fa, b, fb, options) {
+        return solution;
+    }
+
+    let mut x = a;
+    let mut retained = 0i8;
+
+    for i in 0..options.max_iterations {
+        let next = (a * fb - b * fa) / (fb - fa);
+        let fx = f(next);
+        let step = next - x;
+        x = next;
+
+        if !fx.is_finite() {
+            return finish(x, fx, i + 1, Status::NonFinite);
+        }
+        if fx.abs() <= options.residual_tolerance
+            || (i > 0 && step.abs() <= options.tolerance)
+            || (b - a).abs() <= options.tolerance
+        {
+            return finish(x, fx, i + 1, Status::Converged);
+        }
+
+        if fx.signum() == fb.signum() {
+            (b, fb) = (x, fx);
+            if illinois && retained == -1 {
+                fa *= 0.5;
+            }
+            retained = -1;
+        } else {
+            (a, fa) = (x, fx);
+            if illinois && retained == 1 {
+                fb *= 0.5;
+            }
+            retained = 1;
+        }
+    }
+
+    finish(x, f(x), options.max_iterations, Status::MaxIterations)
+}
+
+/// Regula falsi (false position) on a sign-changing bracket `[a, b]`.
+pub fn regula_falsi<F>(f: F, a: f64, b: f64, options: &SolverOptions) -> Solution
+where
+    F: Fn(f64) -> f64,
+{
+    false_position(f, a, b, options, false)
+}
+
+/// The Illinois variant of regula falsi, which avoids the one-sided
+/// convergence of plain false position.
+pub fn illinois<F>(f: F, a: f64, b: f64, options: &SolverOptions) -> Solution
+where
+    F: Fn(f64) -> f64,
+{
+    false_position(f, a, b, options, true)
+}
+
+/// Brent's method on a sign-changing bracket `[a, b]`, combining bisection,
+/// secant and inverse quadratic interpolation steps.
+pub fn brent<F>(f: F, a: f64, b: f64, options: &SolverOptions) -> Solution
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut x_pre, mut x_cur) = (a, b);
+    let (mut f_pre, mut f_cur) = (f(a), f(b));
+    if let Some(solution) = check_bracket(x_pre, f_pre, x_cur, f_cur, options) {
+        return solution;
+    }
+
+    // `x_blk` is the contrapoint: the root always lies between it and `x_cur`.
+    let (mut x_blk, mut f_blk) = (0.0, 0.0);
+    let (mut s_pre, mut s_cur) = (0.0, 0.0);
+
+    for i in 0..options.max_iterations {
+        if f_pre != 0.0 && f_cur != 0.0 && f_pre.signum() != f_cur.signum() {
+            (x_blk, f_blk) = (x_pre, f_pre);
+            s_cur = x_cur - x_pre;
+            s_pre = s_cur;
+        }
+        if f_blk.abs() < f_cur.abs() {
+            (x_pre, x_cur, x_blk) = (x_cur, x_blk, x_cur);
+            (f_pre, f_cur, f_blk) = (f_cur, f_blk, f_cur);
+        }
+
+        let delta = 0.5 * (options.tolerance + 4.0 * f64::EPSILON * x_cur.abs());
+        let s_bis = 0.5 * (x_blk - x_cur);
+        if f_cur.abs() <= options.residual_tolerance || s_bis.abs() < delta {
+            return finish(x_cur, f_cur, i, Status::Converged);
+        }
+
+        if s_pre.abs() > delta && f_cur.abs() < f_pre.abs() {
+            let s_try = if x_pre == x_blk {
+                -f_cur * (x_cur - x_pre) / (f_cur - f_pre)
+            } else {
+                let d_pre = (f_pre - f_cur) / (x_pre - x_cur);
+                let d_blk = (f_blk - f_cur) / (x_blk - x_cur);
+                -f_cur * (f_blk * d_blk - f_pre * d_pre) / (d_blk * d_pre * (f_blk - f_pre))
+            };
+
+            if 2.0 * s_try.abs() < s_pre.abs().min(3.0 * s_bis.abs() - delta) {
+                s_pre = s_cur;
+                s_cur = s_try;
+            } else {
+                s_pre = s_bis;
+                s_cur = s_bis;
+            }
+        } else {
+            s_pre = s_bis;
+            s_cur = s_bis;
+        }
+
+        (x_pre, f_pre) = (x_cur, f_cur);
+        x_cur += if s_cur.abs() > delta {
+            s_cur
+        } else {
+            delta.copysign(s_bis)
+        };
+        f_cur = f(x_cur);
+
+        if !f_cur.is_finite() {
+            return finish(x_cur, f_cur, i + 1, Status::NonFinite);
+        }
+    }
+
+    finish(x_cur, f_cur, options.max_iterations, Status::MaxIterations)
+}
+
+impl Polynomial {
+    /// Newton–Raphson iteration on the polynomial from `x0`, using
+    /// [`Polynomial::differentiate`] for the derivative.
+    pub fn newton(&self, x0: f64, options: &SolverOptions) -> Solution {
+        let derivative = self.differentiate();
+        newton(|x| self.compute(x), |x| derivative.compute(x), x0, options)
+    }
+}
@@ -0,0 +1,291 @@
+use std::cmp::Ordering;
+
+use crate::coefficient::{Coefficient, Field};
+use crate::error::NumericalError;
+use crate::gcd::{euclidean_step, GCD_TOLERANCE};
+use crate::polynomial::Polynomial;
+use crate::rational::Rational;
+
+impl<T: Field + PartialOrd> Polynomial<T> {
+    /// Returns the Sturm sequence `f, f', -rem(f, f'), ...` of the
+    /// square-free part `f` of the polynomial, ending with the last non-zero
+    /// remainder.
+    ///
+    /// Every root of `f` is simple, so with exact coefficient types such as
+    /// [`Rational`] the counts derived from the sequence are exact even for
+    /// repeated roots. Floating-point remainders are trimmed with the
+    /// tolerance of [`Polynomial::gcd`], which can merge close roots, so the
+    /// counting and isolation methods instead build the sequence exactly from
+    /// the rational values of floating-point coefficients. Negative powers of
+    /// `x` are first cleared by multiplying by a power of `x`, which does not
+    /// change the non-zero roots.
+    pub fn sturm_sequence(&self) -> Vec<Polynomial<T>> {
+        let p = self.square_free_part();
+        if p.is_empty() {
+            return Vec::new();
+        }
+
+        let derivative = p.differentiate();
+        let mut sequence = vec![p, derivative];
+        while let [.., previous, last] = &sequence[..] {
+            if last.is_empty() {
+                sequence.pop();
+                break;
+            }
+            let (_, remainder) = euclidean_step(previous, last, GCD_TOLERANCE);
+            sequence.push(-remainder);
+        }
+
+        sequence
+    }
+
+    /// Counts the distinct real roots in the closed interval `[a, b]`, in
+    /// either order, using Sturm's theorem.
+    ///
+    /// Fails with [`NumericalError::InvalidDomain`] for the zero polynomial,
+    /// which vanishes everywhere, for non-finite coefficients and for NaN
+    /// endpoints.
+    pub fn count_real_roots(&self, a: T, b: T) -> Result<usize, NumericalError> {
+        if a.partial_cmp(&b).is_none() {
+            return Err(NumericalError::InvalidDomain(
+                "the endpoints of the interval must be comparable".to_string(),
+            ));
+        }
+        let (a, b) = if b < a { (b, a) } else { (a, b) };
+        let sequence = ExactSequence::new(self)?;
+
+        // Sturm's theorem counts the roots in `(a, b]`, and a root at `a` is
+        // simple in the square-free first member.
+        let count = sequence.variations_at(&a) - sequence.variations_at(&b);
+        Ok(count + usize::from(sequence.vanishes_at(&a)))
+    }
+
+    /// Counts all distinct real roots, using the signs of the Sturm sequence
+    /// at minus and plus infinity.
+    pub fn count_all_real_roots(&self) -> Result<usize, NumericalError> {
+        let sequence = ExactSequence::new(self)?;
+        Ok(sequence.variations_at_infinity(true) - sequence.variations_at_infinity(false))
+    }
+
+    /// Returns upper bounds on the numbers of positive and of negative real
+    /// roots, counted with multiplicity, by Descartes' rule of signs.
+    ///
+    /// Each bound exceeds the true count by an even number.
+    pub fn descartes_bounds(&self) -> (usize, usize) {
+        let positive = variations(self.terms().map(|(_, c)| sign(&c)));
+        let negative = variations(
+            self.terms()
+                .map(|(d, c)| sign(&c) * if d % 2 == 0 { 1 } else { -1 }),
+        );
+        (positive, negative)
+    }
+
+    /// Splits the real line into disjoint half-open intervals `(a, b]` that
+    /// each contain exactly one distinct real root, in ascending order.
+    ///
+    /// Starts from the Cauchy bound and bisects using Sturm counts. With
+    /// floating-point coefficients, an interval that can no longer be split
+    /// is returned as is, even if it holds several close roots. The zero
+    /// polynomial gives no intervals.
+    pub fn isolate_real_roots(&self) -> Vec<(T, T)> {
+        let Ok(sequence) = ExactSequence::new(self) else {
+            return Vec::new();
+        };
+
+        let p = self.without_negative_powers();
+        let lead = abs(p.leading_coefficient());
+        let bound = p
+            .terms()
+            .rev()
+            .skip(1)
+            .map(|(_, c)| abs(c) / lead.clone())
+            .fold(T::zero(), |max, r| if r > max { r } else { max })
+            + T::one();
+        let two = T::from_i32(2);
+
+        let mut intervals = Vec::new();
+        let mut pending = vec![(-bound.clone(), bound)];
+        while let Some((a, b)) = pending.pop() {
+            let count = sequence.variations_at(&a) - sequence.variations_at(&b);
+            let middle = (a.clone() + b.clone()) / two.clone();
+
+            match count {
+                0 => {}
+                1 => intervals.push((a, b)),
+                _ if !(a < middle && middle < b) => intervals.push((a, b)),
+                _ => {
+                    // The upper half is pushed first so the lower half, and
+                    // with it the smallest root, comes off the stack first.
+                    pending.push((middle.clone(), b));
+                    pending.push((a, middle));
+                }
+            }
+        }
+
+        intervals
+    }
+}
+
+/// A non-empty Sturm sequence whose signs are evaluated without rounding.
+///
+/// Exact coefficient types use their own sequence. The only inexact types
+/// with an order are floating point, whose `magnitude` is exact, so their
+/// coefficients and points are converted to the rationals they represent.
+enum ExactSequence<T> {
+    Native(Vec<Polynomial<T>>),
+    Converted(Vec<Polynomial<Rational>>),
+}
+
+impl<T: Field + PartialOrd> ExactSequence<T> {
+    fn new(p: &Polynomial<T>) -> Result<Self, NumericalError> {
+        let sequence = if T::EXACT {
+            Self::Native(p.sturm_sequence())
+        } else {
+            let terms = p
+                .terms()
+                .map(|(d, c)| Some((d, to_rational(&c)?)))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| {
+                    NumericalError::InvalidDomain(
+                        "Sturm sequences need finite coefficients".to_string(),
+                    )
+                })?;
+            Self::Converted(Polynomial::from_sorted_terms(terms).sturm_sequence())
+        };
+
+        let empty = match &sequence {
+            Self::Native(s) => s.is_empty(),
+            Self::Converted(s) => s.is_empty(),
+        };
+        if empty {
+            return Err(NumericalError::InvalidDomain(
+                "the zero polynomial vanishes everywhere".to_string(),
+            ));
+        }
+        Ok(sequence)
+    }
+
+    /// Counts the sign changes at `x`, which may be infinite.
+    fn variations_at(&self, x: &T) -> usize {
+        match self {
+            Self::Native(s) => variations_at(s, x),
+            Self::Converted(s) => match to_rational(x) {
+                Some(x) => variations_at(s, &x),
+                None => variations_at_infinity(s, *x < T::zero()),
+            },
+        }
+    }
+
+    fn variations_at_infinity(&self, negative: bool) -> usize {
+        match self {
+            Self::Native(s) => variations_at_infinity(s, negative),
+            Self::Converted(s) => variations_at_infinity(s, negative),
+        }
+    }
+
+    /// Whether the square-free first member has a root at `x`.
+    fn vanishes_at(&self, x: &T) -> bool {
+        match self {
+            Self::Native(s) => s[0].compute(x.clone()).is_zero(),
+            Self::Converted(s) => to_rational(x).is_some_and(|x| s[0].compute(x).is_zero()),
+        }
+    }
+}
+
+/// Converts a finite floating-point value to the rational it represents.
+fn to_rational<T: Coefficient + PartialOrd>(x: &T) -> Option<Rational> {
+    let magnitude = x.magnitude();
+    Rational::from_f64(if *x < T::zero() {
+        -magnitude
+    } else {
+        magnitude
+    })
+}
+
+impl<T: Coefficient> Polynomial<T> {
+    /// Returns Cauchy's bound `1 + max |a_i / a_n|`, which strictly exceeds
+    /// the modulus of every complex root.
+    ///
+    /// Constants give `0` and the zero polynomial infinity. Negative powers
+    /// of `x` only shift the degrees, so they leave both bounds valid for the
+    /// non-zero roots.
+    pub fn cauchy_bound(&self) -> f64 {
+        let Some(lead) = self.coefficients.last().map(T::magnitude) else {
+            return f64::INFINITY;
+        };
+        if self.len() == 1 {
+            return 0.0;
+        }
+
+        1.0 + self.coefficients[..self.len() - 1]
+            .iter()
+            .map(|c| c.magnitude() / lead)
+            .fold(0.0, f64::max)
+    }
+
+    /// Returns Fujiwara's bound `2 max |a_(n-k) / a_n|^(1/k)`, with the
+    /// constant term halved, which is usually much tighter than Cauchy's.
+    ///
+    /// Edge cases are handled like [`Polynomial::cauchy_bound`].
+    pub fn fujiwara_bound(&self) -> f64 {
+        let (Some(&low), Some(high)) = (self.degrees.first(), self.degree()) else {
+            return f64::INFINITY;
+        };
+        let lead = self.leading_coefficient().magnitude();
+
+        2.0 * self
+            .terms()
+            .filter(|&(d, _)| d < high)
+            .map(|(d, c)| {
+                // The lowest term is the constant once negative powers are
+                // cleared.
+                let halve = d == low && low <= 0;
+                let c = c.magnitude() / if halve { 2.0 } else { 1.0 };
+                (c / lead).powf(1.0 / (high - d) as f64)
+            })
+            .fold(0.0, f64::max)
+    }
+}
+
+fn sign<T: Coefficient + PartialOrd>(x: &T) -> i32 {
+    match x.partial_cmp(&T::zero()) {
+        Some(Ordering::Greater) => 1,
+        Some(Ordering::Less) => -1,
+        _ => 0,
+    }
+}
+
+fn abs<T: Coefficient + PartialOrd>(x: T) -> T {
+    if x < T::zero() {
+        -x
+    } else {
+        x
+    }
+}
+
+/// Counts sign changes, skipping zeros.
+fn variations(signs: impl IntoIterator<Item = i32>) -> usize {
+    signs
+        .into_iter()
+        .filter(|&s| s != 0)
+        .fold((0, 0), |(count, previous), s| {
+            (count + usize::from(previous != 0 && s != previous), s)
+        })
+        .0
+}
+
+fn variations_at<T: Field + PartialOrd>(sequence: &[Polynomial<T>], x: &T) -> usize {
+    variations(sequence.iter().map(|q| sign(&q.compute(x.clone()))))
+}
+
+/// Counts the sign changes at minus or plus infinity, where every member
+/// has the sign of its leading term.
+fn variations_at_infinity<T: Coefficient + PartialOrd>(
+    sequence: &[Polynomial<T>],
+    negative: bool,
+) -> usize {
+    variations(sequence.iter().map(|q| {
+        let odd = q.degree().unwrap_or(0) % 2 != 0;
+        sign(&q.leading_coefficient()) * if negative && odd { -1 } else { 1 }
+    }))
+}
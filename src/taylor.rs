@@ -0,0 +1,127 @@
+use crate::coefficient::{inverse, powi, Coefficient};
+use crate::error::NumericalError;
+use crate::polynomial::Polynomial;
+
+impl<T: Coefficient> Polynomial<T> {
+    /// Returns `p(x + a)`.
+    ///
+    /// Expands each stored term with the binomial theorem, so the cost grows
+    /// with the sum of the degrees present rather than the square of the
+    /// highest degree. Fails with [`NumericalError::InvalidDomain`] if the
+    /// polynomial has negative powers and `a` is not zero, since
+    /// `(x + a)^-1` is not a polynomial.
+    pub fn shift(&self, a: T) -> Result<Polynomial<T>, NumericalError> {
+        if a.is_zero() {
+            return Ok(self.clone());
+        }
+        Ok(Polynomial::from_coefficients(self.taylor_at(a)?))
+    }
+
+    /// Returns `p(s * x)`, scaling the coefficient of `x^d` by `s^d`.
+    ///
+    /// Negative powers need `1 / s`, so with negative powers this fails with
+    /// [`NumericalError::InvalidDomain`] if `s` is zero or, for integer
+    /// coefficient types, anything but `±1`.
+    pub fn scale_argument(&self, s: T) -> Result<Polynomial<T>, NumericalError> {
+        if self.degrees.first().is_some_and(|&d| d < 0) && inverse(&s).is_none() {
+            return Err(NumericalError::InvalidDomain(format!(
+                "negative powers cannot be scaled by {:?}, which has no inverse",
+                s
+            )));
+        }
+
+        Ok(Self::from_sorted_terms(self.terms().map(
+            |(degree, coefficient)| (degree, coefficient * powi(&s, degree)),
+        )))
+    }
+
+    /// Returns the dense coefficients of the polynomial expanded about `a`:
+    /// entry `k` multiplies `(x - a)^k` and equals `p⁽ᵏ⁾(a) / k!`.
+    ///
+    /// The zero polynomial gives an empty vector. Fails like
+    /// [`Polynomial::shift`] if there are negative powers.
+    pub fn taylor_at(&self, a: T) -> Result<Vec<T>, NumericalError> {
+        let Some(high) = self.degree() else {
+            return Ok(Vec::new());
+        };
+        if self.degrees[0] < 0 {
+            return Err(NumericalError::InvalidDomain(
+                "negative powers have no finite Taylor expansion".to_string(),
+            ));
+        }
+
+        // `c x^d` contributes `c C(d, k) a^(d - k)` to `x^k`. Each term
+        // follows from the one above by `* a * k / (d - k + 1)`, and dividing
+        // last keeps the step exact for integer coefficients.
+        let mut dense = vec![T::zero(); high as usize + 1];
+        for (degree, coefficient) in self.terms() {
+            let mut term = coefficient;
+            for k in (0..=degree).rev() {
+                dense[k as usize] = dense[k as usize].clone() + term.clone();
+                if k == 0 {
+                    break;
+                }
+                term = term * a.clone() * T::from_i32(k) / T::from_i32(degree - k + 1);
+                if term.is_zero() {
+                    break;
+                }
+            }
+        }
+
+        Ok(dense)
+    }
+}
+
+impl Polynomial {
+    /// Builds the Taylor polynomial of degree `order` of `f` about `a`,
+    /// expanded in powers of `x`.
+    ///
+    /// The derivatives are estimated by central finite differences refined
+    /// with one step of Richardson extrapolation, so each order loses a few
+    /// digits; orders much above 6 are rarely meaningful in `f64`.
+    pub fn taylor_series<F>(f: F, a: f64, order: usize) -> Polynomial
+    where
+        F: Fn(f64) -> f64,
+    {
+        let scale = a.abs().max(1.0);
+        let mut factorial = 1.0;
+        let mut centred = Vec::with_capacity(order + 1);
+
+        for k in 0..=order {
+            if k > 0 {
+                factorial *= k as f64;
+            }
+            let derivative = if k == 0 {
+                f(a)
+            } else {
+                // The error of the extrapolated difference is O(h^4) against a
+                // rounding error of O(eps / h^k), which balance at this step.
+                let h = f64::EPSILON.powf(1.0 / (k as f64 + 4.0)) * scale;
+                let coarse = central_difference(&f, a, k, h);
+                let fine = central_difference(&f, a, k, h / 2.0);
+                (4.0 * fine - coarse) / 3.0
+            };
+            centred.push(derivative / factorial);
+        }
+
+        Polynomial::from_coefficients(centred)
+            .shift(-a)
+            .expect("non-negative powers always shift")
+    }
+}
+
+/// Estimates the `k`-th derivative of `f` at `a` from `k + 1` samples spaced
+/// `h` apart and centred on `a`, with an error of O(h^2).
+fn central_difference<F: Fn(f64) -> f64>(f: &F, a: f64, k: usize, h: f64) -> f64 {
+    let mut binomial = 1.0;
+    let mut sum = 0.0;
+
+    for j in 0..=k {
+        let x = a + (k as f64 / 2.0 - j as f64) * h;
+        let term = binomial * f(x);
+        sum += if j % 2 == 0 { term } else { -term };
+        binomial = binomial * (k - j) as f64 / (j + 1) as f64;
+    }
+
+    sum / h.powi(k as i32)
+}
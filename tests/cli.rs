@@ -0,0 +1,115 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Runs `numerical` with `args`, feeding `stdin` to it, and returns its exit
+/// code, stdout and stderr.
+fn numerical(args: &[&str], stdin: &str) -> (i32, String, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_numerical"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    (
+        output.status.code().unwrap(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+fn numbers(text: &str) -> Vec<f64> {
+    text.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+#[test]
+fn eval_prints_one_row_per_point() {
+    assert_eq!(
+        numerical(&["eval", "x^2 - 1", "--at", "0, 2"], ""),
+        (0, "0\t-1\n2\t3\n".to_string(), String::new())
+    );
+    assert_eq!(
+        numerical(&["eval", "x^2", "--at", "3", "--format", "json"], "").1,
+        "{\"x\": [3.0], \"y\": [9.0]}\n"
+    );
+
+    let (code, _, stderr) = numerical(&["eval", "x^2", "--at", "a"], "");
+    assert_eq!(code, 2);
+    assert!(stderr.starts_with("error: invalid point: a\n"));
+}
+
+#[test]
+fn polynomials_are_read_from_files_and_stdin() {
+    let path = std::env::temp_dir().join(format!("numerical-cli-{}.json", std::process::id()));
+    std::fs::write(&path, "{\"terms\": [[2, 1.0]]}\n").unwrap();
+    let file = format!("@{}", path.display());
+    let from_file = numerical(&["eval", &file, "--at", "3"], "");
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(from_file, (0, "3\t9\n".to_string(), String::new()));
+
+    assert_eq!(
+        numerical(&["eval", "-", "--at", "2"], "x + 1\n").1,
+        "2\t3\n"
+    );
+
+    let (code, _, stderr) = numerical(&["eval", "@/nonexistent/poly", "--at", "1"], "");
+    assert_eq!(code, 3);
+    assert!(stderr.starts_with("error: cannot read /nonexistent/poly"));
+}
+
+#[test]
+fn roots_lists_real_and_complex_roots() {
+    let (code, stdout, _) = numerical(&["roots", "x^2 - 1", "--real"], "");
+    assert_eq!(code, 0);
+    let roots = numbers(&stdout);
+    assert_eq!(roots.len(), 2);
+    assert!((roots[0] + 1.0).abs() < 1e-12 && (roots[1] - 1.0).abs() < 1e-12);
+
+    assert_eq!(numerical(&["roots", "x^2 + 1"], "").1, "0 + 1i\n0 - 1i\n");
+}
+
+#[test]
+fn fit_reads_crlf_data_from_stdin() {
+    let (code, stdout, _) = numerical(
+        &["fit", "-", "--degree", "1"],
+        "x,y\r\n0,1\r\n1,3\r\n2,5\r\n",
+    );
+    assert_eq!(code, 0);
+    assert!(stdout.starts_with("2x + 1\nR² = 1\n"));
+    assert!(stdout.ends_with("standard errors = 0, 0\n"));
+}
+
+#[test]
+fn fit_errors_point_at_the_offending_row() {
+    let (code, _, stderr) = numerical(&["fit", "-", "--degree", "1"], "x,y\r\n0,1\r\nbad\r\n");
+    assert_eq!(code, 1);
+    assert!(stderr.contains("every row at byte 10\n"), "{}", stderr);
+
+    let (code, _, stderr) = numerical(
+        &["fit", "-", "--degree", "1"],
+        "x,y\r\n0,1\r\n1,3\r\n2,5,2\r\n",
+    );
+    assert_eq!(code, 1);
+    assert!(
+        stderr.contains("no row must have a weight at byte 15\n"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn fit_rejects_degrees_beyond_the_samples() {
+    let (code, _, stderr) = numerical(
+        &["fit", "-", "--degree", "18446744073709551615"],
+        "0,1\n1,2\n",
+    );
+    assert_eq!(code, 1);
+    assert!(stderr.ends_with("needs more than 18446744073709551615 samples, got 2\n"));
+}
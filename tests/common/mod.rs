@@ -0,0 +1,6 @@
+use numerical::Rational;
+
+/// Parses a rational literal such as `-3/4`.
+pub fn rational(s: &str) -> Rational {
+    s.parse().unwrap()
+}
@@ -0,0 +1,96 @@
+use numerical::{Dual, Matrix, Polynomial};
+
+fn laurent() -> Polynomial {
+    Polynomial::from_terms([
+        (-2, 0.5),
+        (-1, 2.0),
+        (0, 1.0),
+        (1, -1.0),
+        (3, 0.5),
+        (7, 1.0),
+    ])
+}
+
+fn assert_close(left: &[f64], right: &[f64]) {
+    assert_eq!(left.len(), right.len());
+    for (l, r) in left.iter().zip(right) {
+        assert!(
+            (l - r).abs() <= 1e-12 * r.abs().max(1.0),
+            "{left:?} != {right:?}"
+        );
+    }
+}
+
+#[test]
+fn paterson_stockmeyer_matches_horner_on_matrices() {
+    let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+
+    for p in [
+        laurent(),
+        Polynomial::from_coefficients(vec![1.0, -3.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 1.0]),
+        Polynomial::constant(4.0),
+    ] {
+        let horner = p.evaluate(&a);
+        let blocked = p.evaluate_paterson_stockmeyer(&a);
+        assert_eq!((blocked.rows(), blocked.cols()), (2, 2));
+        assert_close(blocked.data(), horner.data());
+    }
+}
+
+#[test]
+fn characteristic_polynomial_annihilates_its_matrix() {
+    // Cayley–Hamilton: A^2 - 5A - 2I = 0 for A = [[1, 2], [3, 4]].
+    let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    let characteristic = Polynomial::from_coefficients(vec![-2.0, -5.0, 1.0]);
+
+    assert_close(characteristic.evaluate(&a).data(), &[0.0; 4]);
+    assert_close(
+        characteristic.evaluate_paterson_stockmeyer(&a).data(),
+        &[0.0; 4],
+    );
+}
+
+#[test]
+fn dual_evaluation_matches_derivatives_at() {
+    let p = laurent();
+
+    for x in [-1.5, 0.25, 2.0] {
+        let expected = p.derivatives_at(x, 1);
+        let horner = p.evaluate(&Dual::variable(x));
+        let blocked = p.evaluate_paterson_stockmeyer(&Dual::variable(x));
+
+        assert_close(&[horner.value, horner.derivative], &expected);
+        assert_close(&[blocked.value, blocked.derivative], &expected);
+        assert_close(&[p.value_and_derivative(x).0], &[p.compute(x)]);
+    }
+}
+
+#[test]
+fn exact_negative_powers_evaluate_at_units() {
+    let p: Polynomial<i64> = Polynomial::from_terms([(-1, 3), (0, 1), (2, 2)]);
+
+    assert_eq!(p.compute(1), 6);
+    assert_eq!(p.compute(-1), 0);
+}
+
+#[test]
+#[should_panic(expected = "negative powers need the inverse of x")]
+fn integer_negative_powers_reject_non_units() {
+    let p: Polynomial<i64> = Polynomial::from_terms([(-1, 1), (0, 1)]);
+    p.compute(2);
+}
+
+#[test]
+#[should_panic(expected = "negative powers need the inverse of x")]
+fn exact_negative_powers_reject_zero() {
+    let p: Polynomial<i64> = Polynomial::from_terms([(-1, 1), (0, 1)]);
+    p.compute(0);
+}
+
+#[test]
+fn float_negative_powers_follow_ieee_at_zero() {
+    let p = Polynomial::from_terms([(-1, 1.0), (0, 1.0)]);
+
+    assert_eq!(p.compute(0.0), f64::INFINITY);
+    assert_eq!(p.compute(2.0), 1.5);
+}
@@ -0,0 +1,18 @@
+use numerical::{NumericalError, Polynomial};
+
+#[test]
+fn degrees_without_enough_samples_are_rejected() {
+    let (xs, ys) = ([0.0, 1.0, 2.0], [1.0, 3.0, 7.0]);
+
+    let fit = Polynomial::fit(&xs, &ys, 2).unwrap();
+    assert!(fit.residuals.iter().all(|r| r.abs() < 1e-12));
+
+    for degree in [3, usize::MAX] {
+        match Polynomial::fit(&xs, &ys, degree) {
+            Err(NumericalError::InvalidDomain(message)) => {
+                assert!(message.ends_with("samples, got 3"), "{}", message)
+            }
+            other => panic!("expected an invalid domain error, got {:?}", other),
+        }
+    }
+}
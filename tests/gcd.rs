@@ -0,0 +1,97 @@
+use numerical::{BigInt, Polynomial, Rational};
+
+fn from_roots(roots: &[f64]) -> Polynomial {
+    roots.iter().fold(Polynomial::constant(1.0), |p, &r| {
+        p * Polynomial::from_coefficients(vec![-r, 1.0])
+    })
+}
+
+fn integer(coefficients: &[i64]) -> Polynomial<i64> {
+    Polynomial::from_coefficients(coefficients.to_vec())
+}
+
+fn assert_close(left: &Polynomial, right: &Polynomial) {
+    let difference = left - right;
+    assert!(
+        difference.coefficients().iter().all(|c| c.abs() < 1e-9),
+        "{} != {}",
+        left,
+        right
+    );
+}
+
+#[test]
+fn gcd_finds_a_known_common_factor() {
+    let common = from_roots(&[0.5, -3.0]);
+    let a = &common * &from_roots(&[1.0, 2.0]);
+    let b = &common * &from_roots(&[-1.0]);
+    assert_close(&a.gcd(&b), &common);
+    assert_close(&a.lcm(&b), &(&a * &from_roots(&[-1.0])));
+
+    let a: Polynomial<Rational> = "3x^3 - 3/2x^2 + 2x - 1".parse().unwrap();
+    let b: Polynomial<Rational> = "x^2 - 5/2x + 1".parse().unwrap();
+    let expected: Polynomial<Rational> = "x - 1/2".parse().unwrap();
+    assert_eq!(a.gcd(&b), expected);
+}
+
+#[test]
+fn integer_gcd_keeps_the_common_content() {
+    // 6(x + 1)(x - 2) and 4(x + 1)(x + 3) share 2(x + 1).
+    let a = integer(&[-12, -6, 6]);
+    let b = integer(&[12, 16, 4]);
+    assert_eq!(a.gcd(&b), integer(&[2, 2]));
+    assert_eq!(b.gcd(&a), integer(&[2, 2]));
+
+    assert_eq!(integer(&[1, 0, 1]).gcd(&integer(&[-1, 1])), integer(&[1]));
+
+    // (x + 1)(x^4 + 3) and 3(x + 1)(x - 2) skip degrees in the sequence.
+    let a = integer(&[3, 3, 0, 0, 1, 1]);
+    let b = integer(&[-6, -3, 3]);
+    assert_eq!(a.gcd(&b), integer(&[1, 1]));
+    assert_eq!(
+        integer(&[0, -3, -6]).gcd(&Polynomial::zero()),
+        integer(&[0, 3, 6])
+    );
+
+    let big = |coefficients: &[i64]| -> Polynomial<BigInt> {
+        Polynomial::from_coefficients(coefficients.iter().map(|&c| BigInt::from(c)).collect())
+    };
+    // (x^2 + 1)(x - 3) and (x^2 + 1)(2x + 5).
+    let a = big(&[-3, 1, -3, 1]);
+    let b = big(&[5, 2, 5, 2]);
+    assert_eq!(a.gcd(&b), big(&[1, 0, 1]));
+}
+
+#[test]
+fn extended_gcd_satisfies_the_bezout_identity() {
+    let a: Polynomial<Rational> = "x^4 - 1".parse().unwrap();
+    let b: Polynomial<Rational> = "x^3 + 2x^2 + 2x + 1".parse().unwrap();
+    let (g, s, t) = a.extended_gcd(&b);
+    assert_eq!(g, "x + 1".parse().unwrap());
+    assert_eq!(&s * &a + &t * &b, g);
+
+    let a = from_roots(&[1.0, 2.0, 3.0]);
+    let b = from_roots(&[2.0, -1.0]);
+    let (g, s, t) = a.extended_gcd(&b);
+    assert_close(&g, &from_roots(&[2.0]));
+    assert_close(&(&s * &a + &t * &b), &g);
+}
+
+#[test]
+fn yun_recovers_multiplicities() {
+    let p = from_roots(&[1.0, 1.0, 1.0, -2.0]) * 3.0;
+    let factors = p.square_free_decomposition();
+    assert_eq!(factors.len(), 2);
+    assert_close(&factors[0].0, &from_roots(&[-2.0]));
+    assert_eq!(factors[0].1, 1);
+    assert_close(&factors[1].0, &from_roots(&[1.0]));
+    assert_eq!(factors[1].1, 3);
+
+    let p: Polynomial<Rational> = "x^4 - x^3 - 3x^2 + 5x - 2".parse().unwrap();
+    let factors = p.square_free_decomposition();
+    assert_eq!(
+        factors,
+        [("x + 2".parse().unwrap(), 1), ("x - 1".parse().unwrap(), 3)]
+    );
+    assert_eq!(p.square_free_part(), "x^2 + x - 2".parse().unwrap());
+}
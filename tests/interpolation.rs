@@ -0,0 +1,139 @@
+use numerical::{BarycentricInterpolant, NewtonInterpolant, NumericalError, Polynomial};
+
+const XS: [f64; 5] = [-2.0, -0.5, 0.0, 1.0, 3.0];
+
+fn samples() -> Vec<f64> {
+    XS.iter().map(|x| x.sin() + 0.5 * x).collect()
+}
+
+fn assert_close(left: f64, right: f64) {
+    assert!(
+        (left - right).abs() <= 1e-10 * right.abs().max(1.0),
+        "{} != {}",
+        left,
+        right
+    );
+}
+
+#[test]
+fn interpolants_reproduce_their_samples() {
+    let ys = samples();
+    let p = Polynomial::interpolate(&XS, &ys).unwrap();
+    assert!(p.degree().unwrap() <= 4);
+
+    let mut newton = NewtonInterpolant::new();
+    for (&x, &y) in XS.iter().zip(&ys) {
+        newton.push(x, y).unwrap();
+    }
+    assert_eq!(newton.len(), XS.len());
+    let barycentric = BarycentricInterpolant::new(&XS, &ys).unwrap();
+
+    for (&x, &y) in XS.iter().zip(&ys) {
+        assert_close(p.compute(x), y);
+        assert_close(newton.evaluate(x), y);
+        assert_close(barycentric.evaluate(x), y);
+    }
+}
+
+#[test]
+fn newton_and_barycentric_forms_agree() {
+    let ys = samples();
+    let newton = NewtonInterpolant::from_points(&XS, &ys).unwrap();
+    let barycentric = BarycentricInterpolant::new(&XS, &ys).unwrap();
+
+    for i in 0..=20 {
+        let x = -2.5 + 0.3 * i as f64;
+        assert_close(barycentric.evaluate(x), newton.evaluate(x));
+        assert_close(newton.to_polynomial().compute(x), newton.evaluate(x));
+    }
+    assert_eq!(
+        barycentric.evaluate_many(&XS[..2]),
+        [barycentric.evaluate(XS[0]), barycentric.evaluate(XS[1])]
+    );
+}
+
+#[test]
+fn interpolation_recovers_a_polynomial() {
+    let cubic = Polynomial::from_coefficients(vec![1.0, -2.0, 0.0, 0.5]);
+    let ys = cubic.compute_many(&XS);
+    let p = Polynomial::interpolate(&XS, &ys).unwrap();
+
+    for (a, b) in p.coefficients().iter().zip(cubic.coefficients()) {
+        assert_close(*a, *b);
+    }
+    assert_eq!(p.degree(), Some(3));
+}
+
+#[test]
+fn hermite_matches_values_and_derivatives() {
+    let cubic = Polynomial::from_coefficients(vec![1.0, -2.0, 0.0, 0.5]);
+    let derivative = cubic.differentiate();
+    let second = derivative.differentiate();
+
+    let xs = [-1.0, 2.0];
+    let values = [
+        vec![
+            cubic.compute(-1.0),
+            derivative.compute(-1.0),
+            second.compute(-1.0),
+        ],
+        vec![cubic.compute(2.0)],
+    ];
+    let p = Polynomial::interpolate_hermite(&xs, &values).unwrap();
+
+    assert_eq!(p.degree(), Some(3));
+    for x in [-3.0, 0.0, 1.5] {
+        assert_close(p.compute(x), cubic.compute(x));
+    }
+
+    let q = Polynomial::interpolate_hermite(&[0.0, 1.0], &[[1.0, 0.0], [2.0, 3.0]]).unwrap();
+    let (value, slope) = q.value_and_derivative(1.0);
+    assert_close(q.compute(0.0), 1.0);
+    assert_close(q.differentiate().compute(0.0), 0.0);
+    assert_close(value, 2.0);
+    assert_close(slope, 3.0);
+}
+
+#[test]
+fn repeated_nodes_are_rejected() {
+    let xs = [0.0, 1.0, 0.0];
+    let ys = [1.0, 2.0, 3.0];
+
+    assert!(matches!(
+        Polynomial::interpolate(&xs, &ys),
+        Err(NumericalError::InvalidDomain(_))
+    ));
+    assert!(matches!(
+        BarycentricInterpolant::new(&xs, &ys),
+        Err(NumericalError::InvalidDomain(_))
+    ));
+    assert!(matches!(
+        Polynomial::interpolate_hermite(&xs, &[[1.0], [2.0], [3.0]]),
+        Err(NumericalError::InvalidDomain(_))
+    ));
+
+    let mut newton = NewtonInterpolant::from_points(&xs[..2], &ys[..2]).unwrap();
+    assert!(newton.push(1.0, 5.0).is_err());
+    assert_eq!(newton.len(), 2);
+
+    let empty: [&[f64]; 2] = [&[1.0], &[]];
+    assert!(matches!(
+        Polynomial::interpolate_hermite(&[0.0, 1.0], &empty),
+        Err(NumericalError::InvalidDomain(_))
+    ));
+}
+
+#[test]
+fn length_mismatches_are_rejected() {
+    let (xs, ys) = (&XS[..3], &[1.0, 2.0]);
+    let mismatch = |error| matches!(error, NumericalError::LengthMismatch { left: 3, right: 2 });
+
+    assert!(mismatch(Polynomial::interpolate(xs, ys).unwrap_err()));
+    assert!(mismatch(
+        NewtonInterpolant::from_points(xs, ys).unwrap_err()
+    ));
+    assert!(mismatch(BarycentricInterpolant::new(xs, ys).unwrap_err()));
+    assert!(mismatch(
+        Polynomial::interpolate_hermite(xs, &[[1.0], [2.0]]).unwrap_err()
+    ));
+}
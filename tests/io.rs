@@ -0,0 +1,37 @@
+use numerical::{NumericalError, ParseErrorKind, Polynomial};
+
+#[test]
+fn json_round_trips() {
+    let p = Polynomial::new(vec![1.5, -2.0, 0.25], vec![-1, 0, 3]).unwrap();
+    assert_eq!(Polynomial::from_json(&p.to_json()).unwrap(), p);
+}
+
+#[test]
+fn deeply_nested_json_is_rejected() {
+    let depth = 100_000;
+    let json = format!("{{\"terms\": {}{}}}", "[".repeat(depth), "]".repeat(depth));
+    match Polynomial::from_json(&json) {
+        Err(NumericalError::Parse(error)) => {
+            assert!(matches!(error.kind, ParseErrorKind::InvalidFormat(_)))
+        }
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn long_strings_decode_in_linear_time() {
+    let note = "é".repeat(1_000_000);
+    let json = format!("{{\"note\": \"{}\\é\", \"terms\": [[1, 2.0]]}}", note);
+    assert_eq!(
+        Polynomial::from_json(&json).unwrap(),
+        Polynomial::monomial(2.0, 1)
+    );
+
+    match Polynomial::from_json("{\"terms\": \"é") {
+        Err(NumericalError::Parse(error)) => {
+            assert_eq!(error.offset, 13);
+            assert_eq!(error.kind, ParseErrorKind::UnexpectedEnd);
+        }
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
@@ -0,0 +1,123 @@
+use numerical::{NumericalError, OrthogonalFamily, Polynomial};
+
+const FAMILIES: [OrthogonalFamily; 5] = [
+    OrthogonalFamily::Legendre,
+    OrthogonalFamily::Chebyshev,
+    OrthogonalFamily::Hermite,
+    OrthogonalFamily::Laguerre,
+    OrthogonalFamily::Jacobi {
+        alpha: 0.5,
+        beta: -0.25,
+    },
+];
+
+fn assert_close(left: f64, right: f64) {
+    assert!(
+        (left - right).abs() <= 1e-9 * right.abs().max(1.0),
+        "{} != {}",
+        left,
+        right
+    );
+}
+
+#[test]
+fn known_polynomials() {
+    assert_eq!(
+        Polynomial::legendre(2),
+        Polynomial::from_coefficients(vec![-0.5, 0.0, 1.5])
+    );
+    assert_eq!(
+        Polynomial::chebyshev(3),
+        Polynomial::from_coefficients(vec![0.0, -3.0, 0.0, 4.0])
+    );
+    assert_eq!(
+        Polynomial::hermite(3),
+        Polynomial::from_coefficients(vec![0.0, -12.0, 0.0, 8.0])
+    );
+    assert_eq!(
+        Polynomial::laguerre(2),
+        Polynomial::from_coefficients(vec![1.0, -2.0, 0.5])
+    );
+    assert_eq!(Polynomial::legendre(0), Polynomial::constant(1.0));
+}
+
+#[test]
+fn recurrence_coefficients() {
+    assert_eq!(OrthogonalFamily::Legendre.recurrence(1), (1.5, 0.0, 0.5));
+    assert_eq!(OrthogonalFamily::Chebyshev.recurrence(0), (1.0, 0.0, 0.0));
+    assert_eq!(OrthogonalFamily::Chebyshev.recurrence(4), (2.0, 0.0, 1.0));
+    assert_eq!(OrthogonalFamily::Hermite.recurrence(3), (2.0, 0.0, 6.0));
+    assert_eq!(OrthogonalFamily::Laguerre.recurrence(0), (-1.0, 1.0, 0.0));
+
+    // Jacobi with alpha = beta = 0 is Legendre.
+    let jacobi = OrthogonalFamily::Jacobi {
+        alpha: 0.0,
+        beta: 0.0,
+    };
+    for n in 0..6 {
+        let (a, b, c) = jacobi.recurrence(n);
+        let (la, lb, lc) = OrthogonalFamily::Legendre.recurrence(n);
+        assert_close(a, la);
+        assert_close(b, lb);
+        assert_close(c, lc);
+    }
+}
+
+#[test]
+fn recurrence_evaluation_matches_monomial_form() {
+    for family in FAMILIES {
+        for n in 0..7 {
+            let p = family.polynomial(n);
+            let derivative = p.differentiate();
+            for x in [-0.9, -0.3, 0.0, 0.4, 1.0, 2.5] {
+                let (value, slope) = family.evaluate_with_derivative(n, x);
+                assert_close(value, p.compute(x));
+                assert_close(slope, derivative.compute(x));
+                assert_eq!(family.evaluate(n, x), value);
+            }
+        }
+    }
+}
+
+#[test]
+fn clenshaw_matches_the_expanded_series() {
+    let weights = [0.5, -1.0, 0.25, 2.0, -0.75];
+
+    for family in FAMILIES {
+        let p = family.to_monomial(&weights);
+        for x in [-1.0, -0.2, 0.7, 3.0] {
+            assert_close(family.evaluate_series(&weights, x), p.compute(x));
+        }
+        assert_eq!(family.evaluate_series(&[], 0.5), 0.0);
+    }
+}
+
+#[test]
+fn monomial_conversion_round_trips() {
+    let weights = [1.0, 0.0, -2.0, 0.5, 3.0];
+
+    for family in FAMILIES {
+        let back = family.from_monomial(&family.to_monomial(&weights)).unwrap();
+        assert_eq!(back.len(), weights.len());
+        for (w, expected) in back.iter().zip(weights) {
+            assert_close(*w, expected);
+        }
+    }
+
+    assert_eq!(
+        OrthogonalFamily::Chebyshev
+            .from_monomial(&Polynomial::zero())
+            .unwrap(),
+        Vec::<f64>::new()
+    );
+    assert!(matches!(
+        OrthogonalFamily::Legendre.from_monomial(&Polynomial::monomial(1.0, -1)),
+        Err(NumericalError::InvalidDomain(_))
+    ));
+}
+
+#[test]
+#[should_panic(expected = "Jacobi parameters must exceed -1")]
+fn jacobi_rejects_parameters_at_or_below_minus_one() {
+    Polynomial::jacobi(2, -1.0, 0.5);
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::rational;
+use numerical::{NumericalError, ParseErrorKind, Polynomial, Rational};
+
+#[test]
+fn parses_conventional_notation() {
+    let p: Polynomial = "3x^2 - 2*x + 1.5e-1 - x**-1".parse().unwrap();
+    assert_eq!(
+        p,
+        Polynomial::new(vec![-1.0, 0.15, -2.0, 3.0], vec![-1, 0, 1, 2]).unwrap()
+    );
+    assert!("3x^".parse::<Polynomial>().is_err());
+    assert!("1/2".parse::<Polynomial>().is_err());
+}
+
+#[test]
+fn parses_rational_literals() {
+    let p: Polynomial<Rational> = "x^2 + 1/2x - 3/4".parse().unwrap();
+    assert_eq!(
+        p.coefficients(),
+        &[rational("-3/4"), rational("1/2"), rational("1")]
+    );
+    assert!("x + 1/0".parse::<Polynomial<Rational>>().is_err());
+}
+
+#[test]
+fn plain_display_round_trips() {
+    let p: Polynomial = "-2.5x^3 + x - 1 + 4x^-2".parse().unwrap();
+    assert_eq!(p.to_string().parse::<Polynomial>().unwrap(), p);
+
+    let q: Polynomial<i64> = "-x^4 + 7x^2 - 3".parse().unwrap();
+    assert_eq!(q.to_string().parse::<Polynomial<i64>>().unwrap(), q);
+
+    let r: Polynomial<Rational> = "x + 1/2".parse().unwrap();
+    assert_eq!(r.to_string(), "x + 1/2");
+    assert_eq!(r.to_string().parse::<Polynomial<Rational>>().unwrap(), r);
+}
+
+#[test]
+fn every_textual_notation_round_trips() {
+    use numerical::Notation;
+
+    let p: Polynomial<Rational> = "-3/4x^3 + x^2 - 1/2x + 2 - 5/3x^-1".parse().unwrap();
+    for notation in [Notation::Plain, Notation::Unicode, Notation::Latex] {
+        for text in [
+            p.display(notation).to_string(),
+            format!("{:#}", p.display(notation)),
+        ] {
+            assert_eq!(text.parse::<Polynomial<Rational>>().unwrap(), p, "{}", text);
+        }
+    }
+
+    assert_eq!(p.to_string(), "-(3/4)x^3 + x^2 - (1/2)x + 2 - (5/3)x^-1");
+    assert_eq!(
+        p.unicode().to_string(),
+        "-(3/4)x³ + x² - (1/2)x + 2 - (5/3)x⁻¹"
+    );
+    assert_eq!(
+        p.latex().to_string(),
+        "-\\frac{3}{4}x^{3} + x^{2} - \\frac{1}{2}x + 2 - \\frac{5}{3}x^{-1}"
+    );
+    assert!(p
+        .mathml()
+        .to_string()
+        .contains("<mfrac><mn>3</mn><mn>4</mn></mfrac>"));
+
+    let q: Polynomial = "2.5x² - x^{-2} + 1".parse().unwrap();
+    assert_eq!(q.unicode().to_string().parse::<Polynomial>().unwrap(), q);
+    assert_eq!(q.latex().to_string().parse::<Polynomial>().unwrap(), q);
+}
+
+#[test]
+fn exponents_accept_a_single_level_of_braces() {
+    let p: Polynomial = "x^{ -2 } + x^{3}".parse().unwrap();
+    assert_eq!(p, Polynomial::from_terms([(-2, 1.0), (3, 1.0)]));
+
+    match "x^{{2}}".parse::<Polynomial>() {
+        Err(NumericalError::Parse(error)) => {
+            assert_eq!(error.offset, 3);
+            assert_eq!(error.kind, ParseErrorKind::UnexpectedCharacter('{'));
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    let deep = format!("x^{}", "{".repeat(1_000_000));
+    assert!(deep.parse::<Polynomial>().is_err());
+}
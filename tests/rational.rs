@@ -0,0 +1,61 @@
+mod common;
+
+use common::rational;
+use numerical::{BigInt, Rational};
+
+#[test]
+fn arithmetic_is_exact() {
+    assert_eq!(&rational("1/3") + &rational("1/6"), rational("1/2"));
+    assert_eq!(&rational("2/4") * &rational("-6/9"), rational("-1/3"));
+    assert_eq!(
+        rational("3/-6"),
+        Rational::new(BigInt::from(-1), BigInt::from(2))
+    );
+}
+
+#[test]
+fn to_f64_rounds_correctly() {
+    assert_eq!(rational("1/3").to_f64(), 1.0 / 3.0);
+    assert_eq!(rational("-2/3").to_f64(), -2.0 / 3.0);
+    assert_eq!(rational("1/10").to_f64(), 0.1);
+    assert_eq!(rational("0").to_f64(), 0.0);
+
+    for value in [
+        0.1,
+        -7.25e-3,
+        1e300,
+        123456789.123,
+        f64::MAX,
+        5e-324,
+        2.2e-310,
+    ] {
+        assert_eq!(Rational::from_f64(value).unwrap().to_f64(), value);
+    }
+}
+
+#[test]
+fn to_f64_rounds_once() {
+    let big = |bits: u64| BigInt::from(1).shl(bits);
+
+    // 2^53 + 1 is halfway between two doubles, so ties go to even...
+    let halfway = Rational::from(&big(53) + &BigInt::from(1));
+    assert_eq!(halfway.to_f64(), 2f64.powi(53));
+
+    // ...but the smallest excess above halfway must round up.
+    let numerator = &(&(&big(53) + &BigInt::from(1)) * &big(70)) + &BigInt::from(1);
+    let above = Rational::new(numerator, big(70));
+    assert_eq!(above.to_f64(), 2f64.powi(53) + 2.0);
+
+    // Subnormal results are rounded once, at their own precision.
+    let tiny = Rational::new(BigInt::from(3), big(1076));
+    assert_eq!(tiny.to_f64(), 5e-324);
+    let smallest_half = Rational::new(BigInt::from(1), big(1075));
+    assert_eq!(smallest_half.to_f64(), 0.0);
+}
+
+#[test]
+fn to_f64_overflows_to_infinity() {
+    let huge = Rational::from(BigInt::from(1).shl(1100));
+    assert_eq!(huge.to_f64(), f64::INFINITY);
+    assert_eq!((-huge).to_f64(), f64::NEG_INFINITY);
+}
@@ -0,0 +1,62 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Feeds `input` to `numerical repl` and returns what it prints.
+fn repl(input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_numerical"))
+        .arg("repl")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn juxtaposed_parentheses_multiply() {
+    assert_eq!(repl("(x+1)(x-1)\n"), "x^2 - 1\n");
+    assert_eq!(repl("x(x+1)\n"), "x^2 + x\n");
+    assert_eq!(repl("2x(x+1)\n"), "2x^2 + 2x\n");
+    assert_eq!(repl("3(x+1)\n"), "3x + 3\n");
+}
+
+#[test]
+fn bound_names_evaluate_and_compose() {
+    let output = repl("p = x^2 + 1\np(3)\np(x+1)\np'(2)\n2p(3)\n");
+    assert_eq!(
+        output.lines().skip(1).collect::<Vec<_>>(),
+        ["10", "x^2 + 2x + 2", "4", "20"]
+    );
+}
+
+#[test]
+fn deep_nesting_is_an_error() {
+    let depth = 200_000;
+    let line = format!("{}x{}\n", "(".repeat(depth), ")".repeat(depth));
+    let output = repl(&line);
+    assert!(output.ends_with("error: Invalid format: nested too deeply at byte 128\n"));
+
+    assert_eq!(repl(&format!("{}x\n", "-".repeat(100))), "x\n");
+}
+
+#[test]
+fn degree_overflow_is_an_error() {
+    let output = repl("diff(x^-2147483647, 3)\np = x^2\np^2000000000\nx^2147483647 * x\n");
+    let errors: Vec<&str> = output.lines().filter(|l| l.starts_with("error:")).collect();
+    assert_eq!(
+        errors,
+        [
+            "error: Invalid domain: the degree -2147483650 does not fit in an i32",
+            "error: Invalid domain: the degree 4000000000 does not fit in an i32",
+            "error: Invalid domain: the degree 2147483648 does not fit in an i32",
+        ]
+    );
+}
@@ -0,0 +1,28 @@
+use numerical::Polynomial;
+
+fn from_roots(roots: &[f64]) -> Polynomial {
+    roots.iter().fold(Polynomial::constant(1.0), |p, &r| {
+        p * Polynomial::from_coefficients(vec![-r, 1.0])
+    })
+}
+
+#[test]
+fn close_simple_roots_are_not_merged() {
+    let roots = from_roots(&[1.0, 1.0001]).roots_with_multiplicity();
+    assert_eq!(roots.len(), 2);
+    assert!(roots.iter().all(|r| r.multiplicity == 1));
+    assert!((roots[0].value.re - roots[1].value.re).abs() > 9e-5);
+}
+
+#[test]
+fn multiple_roots_are_merged() {
+    let roots = from_roots(&[0.3, 0.3, 0.3, -2.0]).roots_with_multiplicity();
+    let multiplicities: Vec<usize> = roots.iter().map(|r| r.multiplicity).collect();
+    assert_eq!(roots.len(), 2);
+    assert!(multiplicities.contains(&3));
+
+    let small = from_roots(&[1e-5, 1e-5, 3.0]).roots_with_multiplicity();
+    assert!(small
+        .iter()
+        .any(|r| r.multiplicity == 2 && (r.value.re - 1e-5).abs() < 1e-12));
+}
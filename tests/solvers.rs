@@ -0,0 +1,149 @@
+use numerical::solvers::{self, SolverOptions, Status};
+use numerical::{NumericalError, Polynomial};
+
+fn cubic(x: f64) -> f64 {
+    x * x * x - 2.0 * x - 5.0
+}
+
+const CUBIC_ROOT: f64 = 2.094_551_481_542_326_5;
+
+#[test]
+fn every_solver_finds_the_root_of_a_cubic() {
+    let options = SolverOptions::default();
+    let solutions = [
+        solvers::newton(cubic, |x| 3.0 * x * x - 2.0, 2.0, &options),
+        solvers::secant(cubic, 2.0, 3.0, &options),
+        solvers::bisection(cubic, 2.0, 3.0, &options),
+        solvers::regula_falsi(cubic, 2.0, 3.0, &options),
+        solvers::illinois(cubic, 2.0, 3.0, &options),
+        solvers::brent(cubic, 2.0, 3.0, &options),
+    ];
+
+    for solution in solutions {
+        assert_eq!(solution.status, Status::Converged, "{solution:?}");
+        assert!((solution.root - CUBIC_ROOT).abs() < 1e-9, "{solution:?}");
+        assert!(solution.residual < 1e-8, "{solution:?}");
+    }
+}
+
+#[test]
+fn polynomial_newton_uses_the_derivative() {
+    let p = Polynomial::from_coefficients(vec![-5.0, -2.0, 0.0, 1.0]);
+    let solution = p.newton(2.0, &SolverOptions::default());
+
+    assert!(solution.converged());
+    assert!((solution.into_result().unwrap() - CUBIC_ROOT).abs() < 1e-12);
+}
+
+#[test]
+fn bracketing_solvers_reject_brackets_without_a_sign_change() {
+    let options = SolverOptions::default();
+    let square = |x: f64| x * x + 1.0;
+
+    for solution in [
+        solvers::bisection(square, -1.0, 2.0, &options),
+        solvers::regula_falsi(square, -1.0, 2.0, &options),
+        solvers::illinois(square, -1.0, 2.0, &options),
+        solvers::brent(square, -1.0, 2.0, &options),
+    ] {
+        assert_eq!(solution.status, Status::InvalidBracket);
+        assert_eq!(solution.root, -1.0);
+        assert!(matches!(
+            solution.into_result(),
+            Err(NumericalError::InvalidDomain(_))
+        ));
+    }
+}
+
+#[test]
+fn flat_steps_report_a_zero_derivative() {
+    let options = SolverOptions::default();
+
+    let newton = solvers::newton(|x| x * x - 1.0, |x| 2.0 * x, 0.0, &options);
+    assert_eq!(newton.status, Status::ZeroDerivative);
+    assert_eq!(newton.iterations, 0);
+
+    let secant = solvers::secant(|x| x * x - 1.0, -2.0, 2.0, &options);
+    assert_eq!(secant.status, Status::ZeroDerivative);
+    assert!(matches!(
+        secant.into_result(),
+        Err(NumericalError::NonConvergence { iterations: 0, .. })
+    ));
+}
+
+#[test]
+fn non_finite_values_stop_the_solvers() {
+    let options = SolverOptions::default();
+
+    let newton = solvers::newton(|x| 1.0 / x - 1.0, |_| 1.0, 0.0, &options);
+    assert_eq!(newton.status, Status::NonFinite);
+
+    let bisection = solvers::bisection(f64::ln, 0.0, 2.0, &options);
+    assert_eq!(bisection.status, Status::NonFinite);
+    assert_eq!(bisection.root, 0.0);
+
+    let gap = |x: f64| match x {
+        x if x < 0.25 => -1.0,
+        x if x > 0.75 => 1.0,
+        _ => f64::NAN,
+    };
+    let brent = solvers::brent(gap, 0.0, 1.0, &options);
+    assert_eq!(brent.status, Status::NonFinite);
+
+    let jump = |x: f64| if x > 0.5 { f64::INFINITY } else { x - 1.0 };
+    let secant = solvers::secant(jump, 0.0, 0.25, &options);
+    assert_eq!(secant.status, Status::NonFinite);
+    assert!(secant.into_result().is_err());
+}
+
+#[test]
+fn iteration_limits_are_reported() {
+    let options = SolverOptions {
+        max_iterations: 3,
+        ..SolverOptions::default()
+    };
+
+    let bisection = solvers::bisection(cubic, 2.0, 3.0, &options);
+    assert_eq!(bisection.status, Status::MaxIterations);
+    assert_eq!(bisection.iterations, 3);
+    assert!((bisection.root - CUBIC_ROOT).abs() < 0.25);
+
+    let newton = solvers::newton(|x| x * x + 1.0, |x| 2.0 * x, 2.0, &options);
+    assert_eq!(newton.status, Status::MaxIterations);
+    assert!(matches!(
+        newton.into_result(),
+        Err(NumericalError::NonConvergence { iterations: 3, .. })
+    ));
+}
+
+#[test]
+fn residual_tolerance_accepts_an_endpoint_root() {
+    let options = SolverOptions {
+        residual_tolerance: 1e-12,
+        ..SolverOptions::default()
+    };
+    let solution = solvers::brent(|x| x - 1.0, 1.0, 3.0, &options);
+
+    assert_eq!(solution.status, Status::Converged);
+    assert_eq!((solution.root, solution.iterations), (1.0, 0));
+}
+
+#[test]
+fn illinois_avoids_the_one_sided_stall_of_regula_falsi() {
+    // The convex x^10 - 1 keeps the right end of [0, 1.3] fixed under plain
+    // false position, which then creeps towards 1 from the left.
+    let f = |x: f64| x.powi(10) - 1.0;
+    let options = SolverOptions {
+        max_iterations: 50,
+        ..SolverOptions::default()
+    };
+
+    let plain = solvers::regula_falsi(f, 0.0, 1.3, &options);
+    assert_eq!(plain.status, Status::MaxIterations);
+    assert!(plain.root < 1.0);
+
+    let illinois = solvers::illinois(f, 0.0, 1.3, &options);
+    assert_eq!(illinois.status, Status::Converged);
+    assert!((illinois.root - 1.0).abs() < 1e-12);
+    assert!(illinois.iterations < 20);
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use common::rational;
+use numerical::{Polynomial, Rational};
+
+fn polynomial(s: &str) -> Polynomial<Rational> {
+    s.parse().unwrap()
+}
+
+#[test]
+fn counts_distinct_roots_despite_multiplicity() {
+    // (x - 1)^2 (x - 3)
+    let p = polynomial("x^3 - 5x^2 + 7x - 3");
+    assert_eq!(p.count_real_roots(rational("1"), rational("4")).unwrap(), 2);
+    assert_eq!(p.count_real_roots(rational("0"), rational("1")).unwrap(), 1);
+    assert_eq!(p.count_real_roots(rational("0"), rational("2")).unwrap(), 1);
+    assert_eq!(p.count_all_real_roots().unwrap(), 2);
+
+    // x^2 (x - 2)
+    let q = polynomial("x^3 - 2x^2");
+    assert_eq!(
+        q.count_real_roots(rational("-1"), rational("0")).unwrap(),
+        1
+    );
+    assert_eq!(q.count_real_roots(rational("0"), rational("3")).unwrap(), 2);
+    assert_eq!(q.count_all_real_roots().unwrap(), 2);
+}
+
+#[test]
+fn counts_roots_at_endpoints_once() {
+    // (x - 1)^2 (x - 3)
+    let p = polynomial("x^3 - 5x^2 + 7x - 3");
+    assert_eq!(p.count_real_roots(rational("1"), rational("3")).unwrap(), 2);
+    assert_eq!(p.count_real_roots(rational("3"), rational("1")).unwrap(), 2);
+    assert_eq!(p.count_real_roots(rational("1"), rational("1")).unwrap(), 1);
+    assert_eq!(p.count_real_roots(rational("3"), rational("5")).unwrap(), 1);
+    assert_eq!(
+        p.count_real_roots(rational("1/2"), rational("1")).unwrap(),
+        1
+    );
+    assert_eq!(
+        p.count_real_roots(rational("2"), rational("5/2")).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn isolates_repeated_roots() {
+    // x^2 (x - 1) (x + 1)
+    let p = polynomial("x^4 - x^2");
+    let intervals = p.isolate_real_roots();
+    assert_eq!(intervals.len(), 3);
+    for ((a, b), root) in intervals.into_iter().zip(["-1", "0", "1"]) {
+        let root = rational(root);
+        assert!(a < root && root <= b);
+    }
+
+    // (x - 2)^3 (x^2 + 1)
+    let q = polynomial("x^5 - 6x^4 + 13x^3 - 14x^2 + 12x - 8");
+    assert_eq!(q.isolate_real_roots().len(), 1);
+}
+
+#[test]
+fn float_counts_do_not_merge_close_roots() {
+    // Roots at ±1e-5, which a tolerance-based sequence would merge.
+    let p = Polynomial::from_coefficients(vec![-1e-4, 0.0, 1e6]);
+    assert_eq!(p.count_all_real_roots().unwrap(), 2);
+    assert_eq!(p.count_real_roots(0.0, 1.0).unwrap(), 1);
+    assert_eq!(p.count_real_roots(f64::NEG_INFINITY, 0.0).unwrap(), 1);
+    let intervals = p.isolate_real_roots();
+    assert_eq!(intervals.len(), 2);
+    for ((a, b), root) in intervals.into_iter().zip([-1e-5, 1e-5]) {
+        assert!(a < root && root <= b);
+    }
+
+    // (x - 1)(x - 1.0001) and the repeated root of (x - 1)^2.
+    let q = Polynomial::from_coefficients(vec![1.0001, -2.0001, 1.0]);
+    assert_eq!(q.count_real_roots(0.5, 1.5).unwrap(), 2);
+    assert_eq!(q.isolate_real_roots().len(), 2);
+    let square = Polynomial::from_coefficients(vec![1.0, -2.0, 1.0]);
+    assert_eq!(square.count_all_real_roots().unwrap(), 1);
+    assert_eq!(square.count_real_roots(1.0, 2.0).unwrap(), 1);
+
+    assert!(p.count_real_roots(f64::NAN, 1.0).is_err());
+}
@@ -0,0 +1,40 @@
+use numerical::{NumericalError, Polynomial, Rational};
+
+#[test]
+fn negative_powers_need_an_invertible_scale() {
+    let p = Polynomial::<i64>::new(vec![4, 3], vec![-1, 2]).unwrap();
+    assert!(matches!(
+        p.scale_argument(2),
+        Err(NumericalError::InvalidDomain(_))
+    ));
+    assert!(matches!(
+        p.compose(&Polynomial::monomial(2, 1)),
+        Err(NumericalError::InvalidDomain(_))
+    ));
+    assert_eq!(p.scale_argument(-1).unwrap().to_string(), "3x^2 - 4x^-1");
+
+    let q: Polynomial<Rational> = "4x^-1 + x".parse().unwrap();
+    let two: Rational = "2".parse().unwrap();
+    assert_eq!(q.scale_argument(two).unwrap().to_string(), "2x + 2x^-1");
+}
+
+#[test]
+fn shift_expands_sparse_terms() {
+    let p: Polynomial = "2x^3 - 3x + 5".parse().unwrap();
+    assert_eq!(p.shift(2.0).unwrap().to_string(), "2x^3 + 12x^2 + 21x + 15");
+    assert_eq!(p.taylor_at(-1.5).unwrap(), [2.75, 10.5, -9.0, 2.0]);
+
+    let q: Polynomial<i64> = "2x^5 - 3x^2 + 5".parse().unwrap();
+    assert_eq!(
+        q.shift(-3).unwrap().to_string(),
+        "2x^5 - 30x^4 + 180x^3 - 543x^2 + 828x - 508"
+    );
+
+    let r: Polynomial<Rational> = "x^40 - 7x^3 + 1".parse().unwrap();
+    let a: Rational = "1/3".parse().unwrap();
+    assert_eq!(r.shift(a.clone()).unwrap().shift(-a).unwrap(), r);
+
+    let sparse: Polynomial = Polynomial::new(vec![1.0, 1.0], vec![0, 3000]).unwrap();
+    let shifted = sparse.shift(1e-3).unwrap();
+    assert!((shifted.compute(0.999) - sparse.compute(1.0)).abs() < 1e-12);
+}